@@ -1,5 +1,8 @@
 use std::{
+    cell::Cell,
     fmt,
+    io::{self, Write},
+    net::SocketAddr,
     time::{Duration, Instant},
 };
 
@@ -9,27 +12,131 @@ pub enum InputField {
     Message,
 }
 
+use chrono::{DateTime, Local};
 use crossterm::event;
-use ratatui::text::Text;
+use ratatui::{layout::Rect, text::Text};
 use reqwest::Client;
 use tokio::{
+    net::TcpListener,
     runtime::Handle,
-    sync::mpsc::{self, error::TryRecvError, UnboundedReceiver, UnboundedSender},
+    sync::{
+        mpsc::{self, error::TryRecvError, UnboundedReceiver, UnboundedSender},
+        watch,
+    },
 };
 
 use crate::{
-    components,
+    components::{self, text_input::TextInput, LayoutMode},
+    config::Config,
     database::DatabaseManager,
+    exporter::{self, ExporterSnapshot},
+    http,
+    i18n::{t, tf, tn},
     logger,
-    system::{keys_handler, system_info::System},
-    types::{GeoData, ListState, SystemData, WeatherInfo, WeatherResponse},
+    system::{
+        alerts::{AlertEngine, AlertEvent, AlertEventKind, AlertMetric},
+        editor,
+        history::MetricsHistory,
+        keys_handler,
+        metrics::{Metric, MetricsRecorder, TrendState},
+        processes::ProcessTable,
+        sampler::{Sample, Sampler, SamplerCommand, Subsystems},
+        system_info::System,
+    },
+    types::{
+        Forecast, ForecastMode, GeoData, ListState, Notice, PendingSignal, ProcessSignal,
+        SystemData, WeatherInfo,
+    },
+    units,
+    weather::{
+        cache_key, time_to_live, Backoff, CachedWeather, Location, Provider, WeatherProvider,
+    },
 };
 
+/// Top-level screens, cycled with Tab / Shift-Tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Tasks,
+    Disks,
+    Network,
+    Processes,
+    Sensors,
+    Alerts,
+    History,
+}
+
+impl View {
+    pub const ALL: [View; 7] = [
+        View::Tasks,
+        View::Disks,
+        View::Network,
+        View::Processes,
+        View::Sensors,
+        View::Alerts,
+        View::History,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            View::Tasks => "Tasks",
+            View::Disks => "Disks",
+            View::Network => "Network",
+            View::Processes => "Processes",
+            View::Sensors => "Sensors",
+            View::Alerts => "Alerts",
+            View::History => "History",
+        }
+    }
+
+    /// Subsystems that must be sampled while this view is on screen.
+    pub fn subsystems(self) -> Subsystems {
+        match self {
+            View::Disks => Subsystems::DISKS,
+            View::Network => Subsystems::NETWORK,
+            View::Processes => Subsystems::PROCESSES,
+            View::Sensors => Subsystems::SENSORS,
+            View::Tasks | View::Alerts | View::History => Subsystems::NONE,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|view| *view == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let i = Self::ALL.iter().position(|view| *view == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Length limits of the title and tag fields, in user-perceived characters.
+pub const TITLE_MAX_LEN: usize = 50;
+pub const TAG_MAX_LEN: usize = 24;
+
+/// How often animations advance and notices expire. System data is sampled
+/// in the background at the rates from `Config::refresh`.
+pub const TICK_RATE: Duration = Duration::from_millis(1000);
+
 enum AsyncUpdate {
     Ip(String),
+    /// Tagged with the location generation it was fetched for, so updates
+    /// queued before a location switch are dropped.
+    Weather(u64, WeatherUpdate),
+    System(Box<Sample>),
+}
+
+enum WeatherUpdate {
     Geo(GeoData),
-    Weather(WeatherInfo),
-    Error(String),
+    Current(WeatherInfo),
+    Forecast(Forecast),
+    /// Current weather could not be fetched; retried with backoff.
+    FetchFailed {
+        error: String,
+        retry_in: Duration,
+    },
+    /// Only the forecast failed; the current weather is still fresh.
+    ForecastFailed(String),
 }
 
 pub struct App {
@@ -37,25 +144,91 @@ pub struct App {
     ip: String,
     geo_data: Option<GeoData>,
     weather_data: Option<WeatherInfo>,
+    forecast: Option<Forecast>,
+    /// Whether the weather panel is expanded below the header.
+    pub show_weather: bool,
+    pub forecast_mode: ForecastMode,
+    /// `None` when the configured provider cannot be used.
+    weather_provider: Option<Provider>,
+    /// Saved locations cycled in the header; empty to locate by public IP.
+    locations: Vec<Location>,
+    location_index: usize,
+    /// No outbound requests are made in privacy mode.
+    privacy_mode: bool,
+    /// Commands to the weather task, `None` until it runs or in privacy mode.
+    weather_commands: Option<UnboundedSender<WeatherCommand>>,
+    /// Bumped on every location switch; weather updates of older ones are
+    /// ignored.
+    weather_generation: u64,
+    weather_refresh: Duration,
+    /// When the shown weather was fetched.
+    weather_updated: Option<DateTime<Local>>,
+    /// Whether the shown weather was loaded from the cache.
+    weather_cached: bool,
+    weather_cache_ttl: Duration,
+    /// Last fetch error and when the next attempt is due, until a fetch succeeds.
+    weather_failure: Option<(String, Instant)>,
     pub show_quit_modal: bool,
     pub sys_data: SystemData,
-    pub sys_collector: System,
+    sampler: UnboundedSender<SamplerCommand>,
     pub sys_text: Text<'static>,
+    pub sys_history: MetricsHistory,
+    /// Whether the system panel shows the history sparklines.
+    pub show_sys_history: bool,
+    /// Whether the system panel shows host identity, boot time and load.
+    pub show_host_details: bool,
+    pub alerts: AlertEngine,
+    alert_bell: bool,
+    /// Set when an alert fired; the bell is rung after the next draw.
+    ring_bell: bool,
+    alert_create_todo: bool,
+    /// `None` when metrics recording is disabled.
+    metrics_recorder: Option<MetricsRecorder>,
+    pub trend: TrendState,
+    /// Publishes snapshots to the Prometheus exporter when it is running.
+    exporter: Option<watch::Sender<ExporterSnapshot>>,
     pub animation_frame: usize,
+    /// Set on `Event::Resize` so the next frame starts from a cleared screen.
+    needs_clear: bool,
+    /// Layout for the current terminal size; while it is too small nothing
+    /// but a warning is drawn.
+    pub layout_mode: LayoutMode,
     client: Client,
     runtime: Handle,
     updates_rx: UnboundedReceiver<AsyncUpdate>,
     updates_tx: UnboundedSender<AsyncUpdate>,
+    pub view: View,
     pub list_state: ListState,
+    pub disk_selected: usize,
+    pub network_selected: usize,
+    pub process_table: ProcessTable,
+    /// Signal waiting for confirmation in the process view.
+    pub pending_signal: Option<PendingSignal>,
     pub show_item: bool,
     pub show_help: bool,
     database: DatabaseManager,
     // Input state for adding new TODO
     pub show_add_modal: bool,
-    pub input_title: String,
-    pub input_message: String,
-    pub input_cursor_pos: usize,
-    pub input_current_field: InputField, // 0 for title, 1 for message
+    pub input_title: TextInput,
+    pub input_message: TextInput,
+    pub input_current_field: InputField,
+    pub notice: Option<Notice>,
+    /// Tasks waiting for the delete confirmation.
+    pub pending_delete: Option<Vec<i64>>,
+    /// Scroll offset of the task detail and the task it belongs to; another
+    /// selected task starts at the top.
+    detail_scroll: (i64, u16),
+    /// Furthest the task detail can scroll, known after it is drawn.
+    pub detail_max_scroll: Cell<u16>,
+    /// Tasks waiting for the archive confirmation.
+    pub pending_archive: Option<Vec<i64>>,
+    /// Tag being typed for a bulk tag action.
+    pub tag_input: Option<TextInput>,
+    /// TODO to open in the external editor once the current event is handled.
+    pending_editor: Option<i64>,
+    /// Drafts that failed to parse or save, reused on the next edit of their
+    /// TODO.
+    editor_drafts: editor::Drafts,
 }
 
 impl fmt::Debug for App {
@@ -72,12 +245,16 @@ impl fmt::Debug for App {
 }
 
 impl App {
-    pub fn new(runtime: Handle) -> Self {
-        let client = Client::new();
+    pub fn new(runtime: Handle, config: Config) -> Self {
+        let (client, client_error) = http::build_client_or_fallback(&config.http);
+        if let Some(e) = &client_error {
+            let _ = logger::error(e.to_string());
+        }
         let (updates_tx, updates_rx) = mpsc::unbounded_channel();
-        let mut sys_collector = System::new();
-        let sys_data = sys_collector.get_info();
+        let sys_data = SystemData::default();
         let sys_text = components::format_sys_text(&sys_data);
+        let sys_history = MetricsHistory::new(config.history_window, config.refresh.system);
+        let sampler = spawn_sampler(&runtime, &config, updates_tx.clone());
 
         // Initialize database manager
         let database = match DatabaseManager::new() {
@@ -96,24 +273,62 @@ impl App {
             ip: String::new(),
             geo_data: None,
             weather_data: None,
+            forecast: None,
+            show_weather: false,
+            forecast_mode: ForecastMode::Hourly,
+            weather_provider: None,
+            locations: config.locations,
+            location_index: 0,
+            privacy_mode: config.privacy_mode,
+            weather_commands: None,
+            weather_generation: 0,
+            weather_refresh: config.weather_refresh,
+            weather_updated: None,
+            weather_cached: false,
+            weather_cache_ttl: config.weather_cache_ttl,
+            weather_failure: None,
             show_quit_modal: false,
             sys_data,
-            sys_collector,
+            sampler,
             sys_text,
+            sys_history,
+            show_sys_history: false,
+            show_host_details: false,
+            alerts: AlertEngine::new(config.alert_rules),
+            alert_bell: config.alert_bell,
+            ring_bell: false,
+            alert_create_todo: config.alert_create_todo,
+            metrics_recorder: config.record_metrics.then(MetricsRecorder::new),
+            trend: TrendState::new(),
+            exporter: None,
             animation_frame: 0,
+            needs_clear: false,
+            layout_mode: LayoutMode::Wide,
             client,
             runtime,
             updates_rx,
             updates_tx,
+            view: View::Tasks,
             list_state: ListState::new(),
+            disk_selected: 0,
+            network_selected: 0,
+            process_table: ProcessTable::new(),
+            pending_signal: None,
             show_item: false,
             show_help: false,
             database,
             show_add_modal: false,
-            input_title: String::new(),
-            input_message: String::new(),
-            input_cursor_pos: 0,
+            input_title: TextInput::with_max_len(TITLE_MAX_LEN),
+            input_message: TextInput::multiline(),
             input_current_field: InputField::Title,
+            notice: None,
+            pending_delete: None,
+            pending_archive: None,
+            detail_scroll: (0, 0),
+            detail_max_scroll: Cell::new(0),
+            tag_input: None,
+            pending_editor: None,
+            editor_drafts: editor::Drafts::default(),
         };
 
         // Load todos from database after initialization
@@ -121,32 +336,68 @@ impl App {
             eprintln!("Error loading todos from database: {}", e);
         }
 
+        match Provider::new(config.weather_provider, config.weather_api_key.as_deref()) {
+            Ok(provider) => app.weather_provider = Some(provider),
+            Err(e) => {
+                let _ = logger::error(e.to_string());
+                app.notice = Some(Notice::error(e.to_string()));
+            }
+        }
+
+        if let Some(e) = client_error {
+            app.notice = Some(Notice::error(e.to_string()));
+        }
+
+        if let Some(addr) = config.metrics_addr {
+            app.start_exporter(addr);
+        }
+
         app
     }
 
     pub fn run(mut self, mut terminal: ratatui::DefaultTerminal) -> color_eyre::Result<()> {
         self.running = true;
 
-        self.spawn_initial_fetch();
+        self.spawn_weather_task();
+        let size = terminal.size()?;
+        self.handle_resize(size.width, size.height);
 
-        let tick_rate = Duration::from_millis(1000);
+        let tick_rate = TICK_RATE;
         let mut last_tick = Instant::now();
 
         while self.running {
             self.process_updates();
 
             if last_tick.elapsed() >= tick_rate {
-                self.update_ram_data();
                 self.animation_frame = (self.animation_frame + 1) % 4;
+                if self.notice.as_ref().is_some_and(Notice::is_expired) {
+                    self.notice = None;
+                }
                 last_tick = Instant::now();
             }
 
+            if self.needs_clear {
+                terminal.autoresize()?;
+                terminal.clear()?;
+                self.needs_clear = false;
+            }
+
             terminal.draw(|frame| components::render(frame, &self))?;
+            if std::mem::take(&mut self.ring_bell) {
+                // Through the backend, so it is ordered with the frame output.
+                let backend = terminal.backend_mut();
+                backend.write_all(b"\x07")?;
+                io::Write::flush(backend)?;
+            }
 
             let timeout = tick_rate.saturating_sub(last_tick.elapsed());
             if event::poll(timeout)? {
                 self.handle_events()?;
             }
+
+            if let Some(id) = self.pending_editor.take() {
+                self.edit_in_external_editor(&mut terminal, id)?;
+            }
         }
         Ok(())
     }
@@ -159,6 +410,11 @@ impl App {
 
         // Update the list state with loaded items
         self.list_state.items = items;
+        let items = &self.list_state.items;
+        self.list_state
+            .marked
+            .retain(|id| items.iter().any(|item| item.id == *id));
+        self.list_state.visual_anchor = None;
 
         // Reset selection if needed
         if !self.list_state.items.is_empty() {
@@ -191,22 +447,340 @@ impl App {
             .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
     }
 
+    pub(crate) fn request_editor(&mut self) {
+        if let Some(todo) = self.list_state.selected_item() {
+            self.pending_editor = Some(todo.id);
+        }
+    }
+
+    /// Suspend the TUI, let the user edit the TODO in `$VISUAL`/`$EDITOR` and
+    /// persist the result. A draft that fails to parse is kept on disk and
+    /// reopened on the next edit, so nothing typed is lost.
+    fn edit_in_external_editor(
+        &mut self,
+        terminal: &mut ratatui::DefaultTerminal,
+        id: i64,
+    ) -> color_eyre::Result<()> {
+        let Some(mut todo) = self.list_state.items.iter().find(|t| t.id == id).cloned() else {
+            return Ok(());
+        };
+
+        let path = match self
+            .editor_drafts
+            .open(id, &editor::TodoDocument::from_todo(&todo))
+        {
+            Ok(path) => path,
+            Err(e) => {
+                self.notice = Some(Notice::error(tf("Cannot write draft: {}", &[&e])));
+                return Ok(());
+            }
+        };
+
+        ratatui::restore();
+        let status = editor::launch(&path);
+        *terminal = ratatui::init();
+        self.needs_clear = true;
+
+        match status {
+            Err(e) => {
+                let _ = logger::error(format!("Error launching editor: {e}"));
+                self.editor_drafts.keep(id, path);
+                self.notice = Some(Notice::error(tf(
+                    "Cannot launch '{}': {}",
+                    &[&editor::editor_command(), &e],
+                )));
+                return Ok(());
+            }
+            Ok(status) if !status.success() => {
+                let _ = std::fs::remove_file(&path);
+                self.notice = Some(Notice::info(t(
+                    "Editor exited with an error, changes discarded",
+                )));
+                return Ok(());
+            }
+            Ok(_) => {}
+        }
+
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| editor::TodoDocument::parse(&text));
+
+        match parsed {
+            Ok(document) => {
+                document.apply_to(&mut todo);
+                if let Err(e) = self.database.update_todo(&todo) {
+                    let _ = logger::error(format!("Error updating TODO in DB: {e}"));
+                    self.editor_drafts.keep(id, path);
+                    self.notice = Some(Notice::error(tf("Not saved: {}", &[&e])));
+                    return Ok(());
+                }
+                let _ = std::fs::remove_file(&path);
+                if let Err(e) = self.load_todos_from_db() {
+                    let _ = logger::error(format!("Error loading todos from DB after edit: {e}"));
+                }
+                self.select_todo(id);
+                self.notice = Some(Notice::info(tf("Saved \"{}\"", &[&todo.title])));
+            }
+            Err(e) => {
+                self.notice = Some(Notice::error(tf(
+                    "Not saved: {}. Press E to fix the draft ({})",
+                    &[&e, &path.display()],
+                )));
+                self.editor_drafts.keep(id, path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Scroll offset of the selected task's detail.
+    pub fn detail_scroll(&self) -> u16 {
+        match self.list_state.selected_item() {
+            Some(item) if item.id == self.detail_scroll.0 => self.detail_scroll.1,
+            _ => 0,
+        }
+    }
+
+    /// Scroll the selected task's detail by `lines`, negative to go up.
+    pub(crate) fn scroll_detail(&mut self, lines: i32) {
+        let Some(id) = self.list_state.selected_item().map(|item| item.id) else {
+            return;
+        };
+        let current = i32::from(self.detail_scroll());
+        let max = i32::from(self.detail_max_scroll.get());
+        let scroll = (current + lines).clamp(0, max);
+        self.detail_scroll = (id, u16::try_from(scroll).unwrap_or(0));
+    }
+
+    /// Move the selection to the TODO with `id`, if it is still in the list.
+    pub(crate) fn select_todo(&mut self, id: i64) {
+        if let Some(i) = self.list_state.items.iter().position(|t| t.id == id) {
+            self.list_state.selected = Some(i);
+        }
+    }
+
+    pub(crate) fn bulk_set_status(&mut self, status: crate::types::Status) {
+        let ids = self.list_state.target_ids();
+        let done = tf("Marked {} as {}", &[&task_count(&ids), &t(status.as_str())]);
+        self.apply_bulk(done, &ids, |db, ids| db.bulk_update_status(ids, status));
+    }
+
+    pub(crate) fn bulk_delete(&mut self, ids: &[i64]) {
+        let done = tf("Deleted {}", &[&task_count(ids)]);
+        self.apply_bulk(done, ids, DatabaseManager::bulk_delete);
+    }
+
+    pub(crate) fn bulk_archive(&mut self, ids: &[i64]) {
+        let done = tf("Archived {}", &[&task_count(ids)]);
+        self.apply_bulk(done, ids, DatabaseManager::bulk_archive);
+    }
+
+    pub(crate) fn bulk_add_tag(&mut self, tag: &str) {
+        if tag.trim().is_empty() {
+            return;
+        }
+        let ids = self.list_state.target_ids();
+        let done = tf("Tagged {} #{}", &[&task_count(&ids), &tag.trim()]);
+        self.apply_bulk(done, &ids, |db, ids| db.bulk_add_tag(ids, tag));
+    }
+
+    /// Run a bulk database action on `ids` (usually the marked tasks or the
+    /// task under the cursor), then reload, clear the marks and show `done`.
+    fn apply_bulk(
+        &mut self,
+        done: String,
+        ids: &[i64],
+        action: impl FnOnce(&DatabaseManager, &[i64]) -> Result<(), crate::database::DatabaseError>,
+    ) {
+        if ids.is_empty() {
+            return;
+        }
+        let focused = self.list_state.selected_item().map(|item| item.id);
+
+        if let Err(e) = action(&self.database, ids) {
+            let _ = logger::error(format!("Error in bulk action: {e}"));
+            self.notice = Some(Notice::error(tf("Not saved: {}", &[&e])));
+            return;
+        }
+
+        self.list_state.clear_marks();
+        if let Err(e) = self.load_todos_from_db() {
+            let _ = logger::error(format!(
+                "Error loading todos from DB after bulk action: {e}"
+            ));
+        }
+        if let Some(id) = focused {
+            self.select_todo(id);
+        }
+        self.notice = Some(Notice::info(done));
+    }
+
     pub fn delete_todo_from_db(&self, id: i64) -> Result<(), Box<dyn std::error::Error>> {
         self.database
             .delete_todo(id)
             .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
     }
 
-    fn spawn_initial_fetch(&self) {
-        let client = self.client.clone();
-        let tx = self.updates_tx.clone();
-        let weather_key = std::env::var("WEATHER_API_KEY").ok();
+    /// Serve Prometheus metrics on `addr`. Binding happens right away so a
+    /// taken port is reported on screen instead of failing silently.
+    fn start_exporter(&mut self, addr: SocketAddr) {
+        match self.runtime.block_on(TcpListener::bind(addr)) {
+            Ok(listener) => {
+                let snapshot = ExporterSnapshot::new(&self.sys_data, &self.list_state.items);
+                let (tx, rx) = watch::channel(snapshot);
+                self.runtime.spawn(exporter::serve(listener, rx));
+                self.exporter = Some(tx);
+                let _ = logger::info(format!("Metrics exporter listening on {addr}"));
+            }
+            Err(e) => {
+                let message = format!("Metrics exporter cannot listen on {addr}: {e}");
+                let _ = logger::error(&message);
+                self.notice = Some(Notice::error(message));
+            }
+        }
+    }
+
+    fn spawn_weather_task(&mut self) {
+        if self.privacy_mode {
+            return;
+        }
+        let resume = self.restore_weather_cache();
+        let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+        let task = WeatherTask {
+            client: self.client.clone(),
+            provider: self.weather_provider.clone(),
+            location: self.locations.get(self.location_index).cloned(),
+            generation: self.weather_generation,
+            geo: resume.geo,
+            interval: self.weather_refresh,
+            backoff: Backoff::new(RETRY_BASE, self.weather_refresh.min(RETRY_MAX)),
+            tx: self.updates_tx.clone(),
+        };
+        self.runtime.spawn(task.run(commands_rx, resume.delay));
+        self.weather_commands = Some(commands_tx);
+    }
 
-        self.runtime.spawn(async move {
-            if let Err(err) = fetch_initial_data(client, weather_key, tx.clone()).await {
-                let _ = tx.send(AsyncUpdate::Error(err));
+    /// Fetch the weather now instead of waiting for the next refresh.
+    pub fn refresh_weather(&mut self) {
+        if let Some(commands) = &self.weather_commands {
+            let _ = commands.send(WeatherCommand::Refresh);
+            self.weather_failure = None;
+            self.notice = Some(Notice::info(t("Refreshing weather…")));
+        }
+    }
+
+    /// Switch to the next saved location and load its weather.
+    pub fn next_location(&mut self) {
+        if self.locations.len() < 2 {
+            return;
+        }
+        self.location_index = (self.location_index + 1) % self.locations.len();
+        self.weather_generation += 1;
+        self.geo_data = None;
+        self.weather_data = None;
+        self.forecast = None;
+        self.weather_updated = None;
+        self.weather_cached = false;
+        self.weather_failure = None;
+        if self.weather_commands.is_none() {
+            return;
+        }
+        let location = self.locations.get(self.location_index).cloned();
+        let resume = self.restore_weather_cache();
+        if let Some(commands) = &self.weather_commands {
+            let _ = commands.send(WeatherCommand::SetLocation(
+                location,
+                resume,
+                self.weather_generation,
+            ));
+        }
+    }
+
+    /// Show the cached weather of the current location, marked as stale, and
+    /// tell the weather task where to pick up: the first fetch waits until
+    /// the cache is older than the TTL, and cached coordinates are reused
+    /// while fresh (always for saved locations, which never move).
+    fn restore_weather_cache(&mut self) -> Resume {
+        let location = self.locations.get(self.location_index);
+        let key = cache_key(location);
+        let (fetched_at, body) = match self.database.load_weather_cache(&key) {
+            Ok(Some(entry)) => entry,
+            Ok(None) => return Resume::default(),
+            Err(e) => {
+                let _ = logger::error(format!("Error loading cached weather: {e}"));
+                return Resume::default();
             }
-        });
+        };
+        let (Some(cached), Some(fetched_at)) = (
+            CachedWeather::from_json(&body),
+            DateTime::from_timestamp(fetched_at, 0),
+        ) else {
+            return Resume::default();
+        };
+        let fetched_at = fetched_at.with_timezone(&Local);
+        let delay = time_to_live(fetched_at, Local::now(), self.weather_cache_ttl);
+        let geo = (!delay.is_zero() || location.is_some()).then(|| cached.geo.clone());
+
+        self.geo_data = Some(cached.geo);
+        self.weather_data = cached.weather;
+        self.forecast = cached.forecast;
+        self.weather_updated = Some(fetched_at);
+        self.weather_cached = true;
+        Resume { geo, delay }
+    }
+
+    /// Store the shown weather for the next start and for offline use.
+    fn save_weather_cache(&self) {
+        let (Some(geo), Some(fetched_at)) = (&self.geo_data, self.weather_updated) else {
+            return;
+        };
+        let cached = CachedWeather {
+            geo: geo.clone(),
+            weather: self.weather_data.clone(),
+            forecast: self.forecast.clone(),
+        };
+        let key = cache_key(self.locations.get(self.location_index));
+        let result = cached
+            .to_json()
+            .map_err(|e| e.to_string())
+            .and_then(|body| {
+                self.database
+                    .save_weather_cache(&key, fetched_at.timestamp(), &body)
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            let _ = logger::error(format!("Error caching weather: {e}"));
+        }
+    }
+
+    /// Label of the current saved location with its position, e.g.
+    /// `("Home", 1, 3)`, when several are configured.
+    pub fn location_position(&self) -> Option<(String, usize, usize)> {
+        (self.locations.len() > 1).then(|| {
+            (
+                self.locations[self.location_index].label(),
+                self.location_index + 1,
+                self.locations.len(),
+            )
+        })
+    }
+
+    pub fn privacy_mode(&self) -> bool {
+        self.privacy_mode
+    }
+
+    /// Age of the shown weather once it is overdue for a refresh or the
+    /// last fetch failed, i.e. when it should be flagged as stale.
+    pub fn weather_stale_age(&self) -> Option<chrono::Duration> {
+        let age = Local::now() - self.weather_updated?;
+        let overdue = age.to_std().is_ok_and(|age| age > self.weather_refresh);
+        (overdue || self.weather_cached || self.weather_failure.is_some()).then_some(age)
+    }
+
+    /// Seconds until the next retry after a failed fetch.
+    pub fn weather_retry_in(&self) -> Option<u64> {
+        self.weather_failure
+            .as_ref()
+            .map(|(_, at)| at.saturating_duration_since(Instant::now()).as_secs())
     }
 
     fn process_updates(&mut self) {
@@ -226,30 +800,254 @@ impl App {
                 self.ip = ip;
                 let _ = logger::info(message);
             }
-            AsyncUpdate::Geo(geo) => {
+            AsyncUpdate::Weather(generation, update) if generation == self.weather_generation => {
+                self.handle_weather_update(update)
+            }
+            AsyncUpdate::Weather(..) => {}
+            AsyncUpdate::System(sample) => self.apply_sample(*sample),
+        }
+    }
+
+    fn handle_weather_update(&mut self, update: WeatherUpdate) {
+        match update {
+            WeatherUpdate::Geo(geo) => {
                 let message = format!("Latitude: {}, Longitude: {}", geo.latitude, geo.longitude);
                 self.geo_data = Some(geo);
                 let _ = logger::info(message);
             }
-            AsyncUpdate::Weather(weather) => {
+            WeatherUpdate::Current(weather) => {
                 let message = format!("Weather: {} ({:.0}°C)", weather.name, weather.temp_c);
                 self.weather_data = Some(weather);
+                self.weather_updated = Some(Local::now());
+                self.weather_cached = false;
+                self.weather_failure = None;
+                self.save_weather_cache();
+                let _ = logger::info(message);
+            }
+            WeatherUpdate::Forecast(forecast) => {
+                let message = format!(
+                    "Forecast: {} hours, {} days",
+                    forecast.hourly.len(),
+                    forecast.daily.len()
+                );
+                self.forecast = Some(forecast);
+                if !self.weather_cached {
+                    self.save_weather_cache();
+                }
                 let _ = logger::info(message);
             }
-            AsyncUpdate::Error(error) => {
-                let _ = logger::error(error);
+            WeatherUpdate::FetchFailed { error, retry_in } => {
+                let _ = logger::error(format!(
+                    "Weather fetch failed, retrying in {}s: {error}",
+                    retry_in.as_secs()
+                ));
+                self.weather_failure = Some((error, Instant::now() + retry_in));
+            }
+            WeatherUpdate::ForecastFailed(error) => {
+                let _ = logger::error(format!("Forecast fetch failed: {error}"));
             }
         }
     }
 
+    /// Public IP reported by the initial fetch, once it has arrived.
+    pub fn public_ip(&self) -> Option<&str> {
+        Some(self.ip.as_str()).filter(|ip| !ip.is_empty())
+    }
+
     pub fn weather_info(&self) -> Option<&WeatherInfo> {
         self.weather_data.as_ref()
     }
 
-    fn update_ram_data(&mut self) {
-        let updated = self.sys_collector.get_info();
-        self.sys_text = components::format_sys_text(&updated);
-        self.sys_data = updated;
+    pub fn forecast(&self) -> Option<&Forecast> {
+        self.forecast.as_ref()
+    }
+
+    pub fn toggle_forecast_mode(&mut self) {
+        self.forecast_mode = match self.forecast_mode {
+            ForecastMode::Hourly => ForecastMode::Daily,
+            ForecastMode::Daily => ForecastMode::Hourly,
+        };
+    }
+
+    /// Take over a snapshot from the background sampler. History, alerts
+    /// and recorded metrics advance with the CPU/memory samples only, so
+    /// they keep a steady interval whatever the other refresh rates are.
+    fn apply_sample(&mut self, sample: Sample) {
+        if let Some(processes) = sample.processes {
+            self.process_table.set_processes(processes);
+        }
+        if sample.refreshed == Subsystems::PROCESSES {
+            return;
+        }
+
+        self.sys_text = components::format_sys_text(&sample.data);
+        self.sys_data = sample.data;
+        if let Some(exporter) = &self.exporter {
+            exporter.send_replace(ExporterSnapshot::new(
+                &self.sys_data,
+                &self.list_state.items,
+            ));
+        }
+        self.sys_history.push(&self.sys_data, sample.refreshed);
+        if !sample.refreshed.contains(Subsystems::SYSTEM) {
+            return;
+        }
+
+        let events = self.alerts.evaluate(&self.sys_data, Instant::now());
+        self.handle_alerts(events);
+        self.record_metrics();
+        if self.view == View::History {
+            self.load_trend();
+        }
+    }
+
+    /// Write a raw sample when one is due and periodically roll samples up
+    /// into the per-minute and hourly tiers.
+    fn record_metrics(&mut self) {
+        let Some(recorder) = self.metrics_recorder.as_mut() else {
+            return;
+        };
+        let now = Instant::now();
+        let ts = Local::now().timestamp();
+
+        if recorder.sample_due(now) {
+            let samples: Vec<(Metric, f64)> = Metric::ALL
+                .iter()
+                .filter_map(|metric| metric.value(&self.sys_data).map(|value| (*metric, value)))
+                .collect();
+            if let Err(e) = self.database.record_metrics(ts, &samples) {
+                let _ = logger::error(format!("Error recording metrics: {}", e));
+            }
+        }
+        if recorder.rollup_due(now) {
+            if let Err(e) = self.database.rollup_metrics(ts) {
+                let _ = logger::error(format!("Error rolling up metrics: {}", e));
+            }
+        }
+    }
+
+    /// Reload the series charted by the history view.
+    pub fn load_trend(&mut self) {
+        let now = Local::now().timestamp();
+        let from = now - self.trend.range.seconds();
+        match self
+            .database
+            .load_metric_series(self.trend.metric, from, now)
+        {
+            Ok(series) => {
+                self.trend.points = series
+                    .into_iter()
+                    .map(|(ts, value)| (ts as f64, value))
+                    .collect();
+            }
+            Err(e) => {
+                let _ = logger::error(format!("Error loading metric history: {}", e));
+                self.trend.points.clear();
+            }
+        }
+    }
+
+    /// Surface fired and resolved alerts: a notice, the optional terminal
+    /// bell and, if configured, a follow-up TODO for every fired alert.
+    fn handle_alerts(&mut self, events: Vec<AlertEvent>) {
+        for event in events {
+            match event.kind {
+                AlertEventKind::Fired => {
+                    let _ = logger::error(format!("Alert: {}", event.message));
+                    self.notice = Some(Notice::error(format!("⚠ {}", event.message)));
+                    self.ring_bell |= self.alert_bell;
+                    if self.alert_create_todo {
+                        self.create_alert_todo(&event);
+                    }
+                }
+                AlertEventKind::Resolved => {
+                    let _ = logger::info(format!("Alert resolved: {}", event.message));
+                    self.notice = Some(Notice::info(format!("✓ {}", event.message)));
+                }
+            }
+        }
+    }
+
+    fn create_alert_todo(&mut self, event: &AlertEvent) {
+        // Same limit as a title typed in the add modal.
+        let mut title = TextInput::with_max_len(TITLE_MAX_LEN);
+        title.set_value(&format!("Alert: {}", event.message));
+        let message = format!(
+            "{}\n\nRaised at {}.",
+            event.message,
+            units::format_datetime(&event.at)
+        );
+
+        if let Err(e) = self.add_todo_to_db(title.value(), &message, crate::types::Status::Todo) {
+            let _ = logger::error(format!("Error adding alert TODO to DB: {}", e));
+            return;
+        }
+
+        // The alert arrives in the background, keep the cursor and a range
+        // being selected where the user left them.
+        let items = &self.list_state.items;
+        let focused = self.list_state.selected_item().map(|item| item.id);
+        let anchor = self
+            .list_state
+            .visual_anchor
+            .and_then(|i| items.get(i))
+            .map(|item| item.id);
+        if let Err(e) = self.load_todos_from_db() {
+            let _ = logger::error(format!("Error loading todos from DB after alert: {}", e));
+        }
+        if let Some(id) = focused {
+            self.select_todo(id);
+        }
+        self.list_state.visual_anchor =
+            anchor.and_then(|id| self.list_state.items.iter().position(|t| t.id == id));
+    }
+
+    /// Switch the visible view and tell the sampler what is on screen now;
+    /// subsystems that were hidden for a while are refreshed right away.
+    pub fn set_view(&mut self, view: View) {
+        self.view = view;
+        let _ = self
+            .sampler
+            .send(SamplerCommand::Display(view.subsystems()));
+        if view == View::History {
+            self.load_trend();
+        }
+    }
+
+    /// Ask for confirmation before sending `signal` to the selected process.
+    pub fn request_signal(&mut self, signal: ProcessSignal) {
+        if let Some(process) = self.process_table.selected_process() {
+            self.pending_signal = Some(PendingSignal {
+                pid: process.pid,
+                name: process.name.clone(),
+                start_time: process.start_time,
+                signal,
+            });
+        }
+    }
+
+    pub fn send_signal(&mut self, pending: &PendingSignal) {
+        match System::send_signal(pending) {
+            Ok(()) => {
+                let _ = logger::info(format!(
+                    "Sent {} to {} ({})",
+                    pending.signal.as_str(),
+                    pending.name,
+                    pending.pid
+                ));
+                self.notice = Some(Notice::info(tf(
+                    "Sent {} to {} ({})",
+                    &[&pending.signal.as_str(), &pending.name, &pending.pid],
+                )));
+            }
+            Err(e) => {
+                let _ = logger::error(format!("Error sending signal: {e}"));
+                self.notice = Some(Notice::error(e));
+            }
+        }
+        let _ = self
+            .sampler
+            .send(SamplerCommand::Refresh(Subsystems::PROCESSES));
     }
 
     fn handle_events(&mut self) -> color_eyre::Result<()> {
@@ -257,34 +1055,201 @@ impl App {
         Ok(())
     }
 
+    pub(crate) fn handle_resize(&mut self, width: u16, height: u16) {
+        let mode = LayoutMode::for_area(Rect::new(0, 0, width, height));
+        if mode != self.layout_mode {
+            let _ = logger::info(format!("Layout {mode:?} at {width}x{height}"));
+            self.layout_mode = mode;
+        }
+        self.needs_clear = true;
+    }
+
     pub(crate) fn request_quit(&mut self) {
         self.running = false;
     }
 }
 
-async fn fetch_initial_data(
+/// `n tasks` in the current language, for notices about bulk actions.
+fn task_count(ids: &[i64]) -> String {
+    tn("{} task", "{} tasks", ids.len() as u64)
+}
+
+/// Start the background sampler. Subsystems feeding alerts, recorded
+/// metrics or the exporter are sampled even while their view is hidden.
+fn spawn_sampler(
+    runtime: &Handle,
+    config: &Config,
+    tx: UnboundedSender<AsyncUpdate>,
+) -> UnboundedSender<SamplerCommand> {
+    let mut background = Subsystems::SYSTEM;
+    if config.record_metrics || config.metrics_addr.is_some() {
+        background = background | Subsystems::DISKS | Subsystems::NETWORK;
+    } else if config
+        .alert_rules
+        .iter()
+        .any(|rule| rule.metric == AlertMetric::Disk)
+    {
+        background = background | Subsystems::DISKS;
+    }
+    if config.metrics_addr.is_some() {
+        background = background | Subsystems::SENSORS;
+    }
+
+    let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+    let sampler = Sampler::new(config.refresh, background);
+    runtime.spawn(sampler.run(commands_rx, move |sample| {
+        tx.send(AsyncUpdate::System(Box::new(sample))).is_ok()
+    }));
+    commands_tx
+}
+
+enum WeatherCommand {
+    /// Fetch right away, e.g. on a key press.
+    Refresh,
+    /// Switch to another location, `None` to locate by public IP, and tag
+    /// updates with the given generation from now on.
+    SetLocation(Option<Location>, Resume, u64),
+}
+
+/// Where the weather task picks up after the cache has been shown.
+#[derive(Debug, Default)]
+struct Resume {
+    /// Cached coordinates that need not be looked up again.
+    geo: Option<GeoData>,
+    /// Until the first fetch.
+    delay: Duration,
+}
+
+/// First retry delay after a failed fetch; it doubles up to the refresh
+/// interval or `RETRY_MAX`, whichever is shorter.
+const RETRY_BASE: Duration = Duration::from_secs(5);
+const RETRY_MAX: Duration = Duration::from_secs(600);
+
+/// Keeps location and weather up to date: refreshes every `interval`,
+/// retries failures with backoff and reacts to commands from the UI.
+struct WeatherTask {
     client: Client,
-    weather_key: Option<String>,
+    provider: Option<Provider>,
+    location: Option<Location>,
+    /// Location generation sent along with every update.
+    generation: u64,
+    /// Coordinates of the current location once resolved.
+    geo: Option<GeoData>,
+    interval: Duration,
+    backoff: Backoff,
     tx: UnboundedSender<AsyncUpdate>,
-) -> Result<(), String> {
-    let ip = fetch_ip(&client).await.map_err(|e| e.to_string())?;
-    let _ = tx.send(AsyncUpdate::Ip(ip.clone()));
+}
 
-    let geo = fetch_geo(&client, &ip).await.map_err(|e| e.to_string())?;
-    let _ = tx.send(AsyncUpdate::Geo(geo.clone()));
+enum WeatherStep {
+    Fetched(Result<GeoData, String>),
+    Command(Option<WeatherCommand>),
+}
 
-    if let Some(key) = weather_key {
-        match fetch_weather(&client, geo.latitude, geo.longitude, &key).await {
-            Ok(weather) => {
-                let _ = tx.send(AsyncUpdate::Weather(weather));
+impl WeatherTask {
+    /// Fetch after `delay`, then keep refreshing until the UI is gone.
+    async fn run(mut self, mut commands: UnboundedReceiver<WeatherCommand>, mut delay: Duration) {
+        loop {
+            let command = tokio::select! {
+                () = tokio::time::sleep(delay) => None,
+                command = commands.recv() => Some(command),
+            };
+            if let Some(command) = command {
+                match self.apply(command) {
+                    Some(next) => {
+                        delay = next;
+                        continue;
+                    }
+                    None => return,
+                }
             }
-            Err(err) => {
-                let _ = tx.send(AsyncUpdate::Error(err.to_string()));
+
+            // A command cancels the fetch in flight; updates of the previous
+            // location that are already queued are dropped by generation.
+            let step = tokio::select! {
+                result = self.fetch() => WeatherStep::Fetched(result),
+                command = commands.recv() => WeatherStep::Command(command),
+            };
+            delay = match step {
+                WeatherStep::Fetched(Ok(geo)) => {
+                    self.geo = Some(geo);
+                    self.backoff.reset();
+                    self.interval
+                }
+                WeatherStep::Fetched(Err(error)) => {
+                    let retry_in = self.backoff.next_delay();
+                    if !self.send(WeatherUpdate::FetchFailed { error, retry_in }) {
+                        return;
+                    }
+                    retry_in
+                }
+                WeatherStep::Command(command) => match self.apply(command) {
+                    Some(next) => next,
+                    None => return,
+                },
+            };
+        }
+    }
+
+    /// Delay until the next fetch, `None` once the UI is gone.
+    fn apply(&mut self, command: Option<WeatherCommand>) -> Option<Duration> {
+        self.backoff.reset();
+        match command? {
+            WeatherCommand::Refresh => Some(Duration::ZERO),
+            WeatherCommand::SetLocation(location, resume, generation) => {
+                self.location = location;
+                self.generation = generation;
+                self.geo = resume.geo;
+                Some(resume.delay)
             }
         }
     }
 
-    Ok(())
+    /// `false` once the UI is gone.
+    fn send(&self, update: WeatherUpdate) -> bool {
+        self.tx
+            .send(AsyncUpdate::Weather(self.generation, update))
+            .is_ok()
+    }
+
+    /// Resolve the location and fetch current weather and forecast. Only a
+    /// failure of the location or current weather counts as a failed fetch;
+    /// a failed forecast is reported on its own.
+    async fn fetch(&self) -> Result<GeoData, String> {
+        let geo = match (&self.geo, &self.location) {
+            (Some(geo), _) => geo.clone(),
+            // A configured location keeps the public IP away from third parties.
+            (None, Some(location)) => location
+                .resolve(&self.client)
+                .await
+                .map_err(|e| e.to_string())?,
+            (None, None) => {
+                let ip = fetch_ip(&self.client).await.map_err(|e| e.to_string())?;
+                let _ = self.tx.send(AsyncUpdate::Ip(ip.clone()));
+                fetch_geo(&self.client, &ip)
+                    .await
+                    .map_err(|e| e.to_string())?
+            }
+        };
+        if self.geo.is_none() {
+            self.send(WeatherUpdate::Geo(geo.clone()));
+        }
+
+        if let Some(provider) = &self.provider {
+            let (current, forecast) = tokio::join!(
+                provider.current(&self.client, &geo),
+                provider.forecast(&self.client, &geo)
+            );
+            let current = current.map(|weather| {
+                self.send(WeatherUpdate::Current(weather));
+            });
+            self.send(match forecast {
+                Ok(forecast) => WeatherUpdate::Forecast(forecast),
+                Err(e) => WeatherUpdate::ForecastFailed(e.to_string()),
+            });
+            current.map_err(|e| e.to_string())?;
+        }
+        Ok(geo)
+    }
 }
 
 async fn fetch_ip(client: &Client) -> Result<String, reqwest::Error> {
@@ -301,28 +1266,3 @@ async fn fetch_geo(client: &Client, ip: &str) -> Result<GeoData, reqwest::Error>
     let url = format!("https://ipapi.co/{ip}/json/");
     client.get(url).send().await?.json::<GeoData>().await
 }
-
-async fn fetch_weather(
-    client: &Client,
-    latitude: f64,
-    longitude: f64,
-    api_key: &str,
-) -> Result<WeatherInfo, reqwest::Error> {
-    let url = format!(
-        "https://api.openweathermap.org/data/2.5/weather?lat={latitude}&lon={longitude}&appid={api_key}"
-    );
-
-    let resp = client
-        .get(url)
-        .send()
-        .await?
-        .json::<WeatherResponse>()
-        .await?;
-
-    let temp_c = (resp.main.temp - 273.15).round();
-
-    Ok(WeatherInfo {
-        name: resp.name,
-        temp_c,
-    })
-}
@@ -0,0 +1,143 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, List, ListItem, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::{
+    i18n::{self, t, tf},
+    system::alerts::{AlertEngine, AlertEventKind, AlertState},
+    units,
+};
+
+pub fn render_alerts(frame: &mut Frame, area: Rect, alerts: &AlertEngine) {
+    let firing = alerts.firing_count();
+    let title = if firing > 0 {
+        Line::from(vec![
+            Span::raw(t("[== ALERTS ")),
+            Span::styled(tf("{} firing", &[&firing]), Style::default().fg(Color::Red)),
+            Span::raw(" ==]"),
+        ])
+    } else {
+        Line::from(Span::raw(t("[== ALERTS ==]")))
+    }
+    .bold()
+    .centered();
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
+        .title(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rules_height = alerts.rules.len().max(1) as u16 + 1;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(rules_height + 1), Constraint::Min(2)])
+        .split(inner);
+
+    render_rules(frame, chunks[0], alerts);
+    render_log(frame, chunks[1], alerts);
+}
+
+fn render_rules(frame: &mut Frame, area: Rect, alerts: &AlertEngine) {
+    if alerts.rules.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                t("All alert rules are disabled"),
+                Style::default().fg(Color::DarkGray),
+            ))
+            .centered(),
+            area,
+        );
+        return;
+    }
+
+    let titles = ["Metric", "Rule", "Current", "State"];
+    let header = Row::new(
+        titles.map(|title| Cell::from(t(title)).style(Style::default().fg(Color::DarkGray).bold())),
+    );
+
+    let rows = alerts.rules.iter().map(|status| {
+        let (state, style) = match status.state {
+            AlertState::Normal => (t("ok").to_string(), Style::default().fg(Color::Green)),
+            AlertState::Pending(since) => (
+                tf("pending {}s", &[&since.elapsed().as_secs()]),
+                Style::default().fg(Color::Yellow),
+            ),
+            AlertState::Firing(since) => (
+                tf("FIRING {}s", &[&since.elapsed().as_secs()]),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+        };
+        let value = status
+            .value
+            .map_or_else(|| "-".to_string(), |value| format!("{value:.0}%"));
+
+        Row::new(vec![
+            Cell::from(t(status.rule.metric.as_str())),
+            Cell::from(status.rule.describe()).style(Style::default().fg(Color::Gray)),
+            Cell::from(value),
+            Cell::from(state).style(style),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(i18n::fit(titles[0], 7) + 1),
+            Constraint::Length(16),
+            Constraint::Length(i18n::fit(titles[2], 8) + 1),
+            Constraint::Min(12),
+        ],
+    )
+    .header(header);
+    frame.render_widget(table, area);
+}
+
+fn render_log(frame: &mut Frame, area: Rect, alerts: &AlertEngine) {
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(50, 50, 50)))
+        .title(Span::styled(
+            t(" Log "),
+            Style::default().fg(Color::DarkGray),
+        ));
+
+    if alerts.log.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                t("No alerts yet"),
+                Style::default().fg(Color::DarkGray),
+            ))
+            .centered()
+            .block(block),
+            area,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = alerts
+        .log
+        .iter()
+        .rev()
+        .map(|event| {
+            let (mark, color) = match event.kind {
+                AlertEventKind::Fired => ("⚠", Color::Red),
+                AlertEventKind::Resolved => ("✓", Color::Green),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", units::format_clock(&event.at)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(format!("{mark} "), Style::default().fg(color)),
+                Span::raw(event.message.clone()),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(block), area);
+}
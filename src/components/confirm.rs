@@ -0,0 +1,160 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{
+    components,
+    i18n::{self, t, tf},
+    types::PendingSignal,
+};
+
+pub fn render_delete_modal(frame: &mut Frame, count: usize) {
+    let question = if count == 1 {
+        t("Delete this task?").to_string()
+    } else {
+        tf("Delete {} tasks?", &[&count])
+    };
+    render_bulk_modal(frame, t("✖ Delete"), &question);
+}
+
+/// Archived tasks are hidden from the list for good, so this asks first too.
+pub fn render_archive_modal(frame: &mut Frame, count: usize) {
+    let question = if count == 1 {
+        t("Archive this task?").to_string()
+    } else {
+        tf("Archive {} tasks?", &[&count])
+    };
+    render_bulk_modal(frame, t("▣ Archive"), &question);
+}
+
+fn render_bulk_modal(frame: &mut Frame, title: &str, question: &str) {
+    let min_width = 34.max(i18n::width(question) + i18n::width(t(" Y/N")) + 6);
+    let modal_area = components::center_rect_min(30, 15, min_width, 7, frame.area());
+
+    frame.render_widget(Clear, modal_area);
+
+    let modal_block = Block::default()
+        .title(
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(
+                    title,
+                    Style::default()
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" "),
+            ])
+            .alignment(Alignment::Center),
+        )
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Red))
+        .style(Style::default().bg(Color::Rgb(30, 30, 40)));
+
+    frame.render_widget(modal_block, modal_area);
+
+    let content_area = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .split(modal_area);
+
+    let question = Paragraph::new(vec![Line::from(vec![
+        Span::styled(
+            question.to_string(),
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            t(" Y/N"),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::ITALIC),
+        ),
+    ])
+    .alignment(Alignment::Center)])
+    .style(Style::default().bg(Color::Rgb(30, 30, 40)));
+
+    let controls = Paragraph::new(Line::from(t("ESC (Cancel)")).alignment(Alignment::Center))
+        .style(Style::default().bg(Color::Rgb(30, 30, 40)));
+
+    frame.render_widget(question, content_area[0]);
+    frame.render_widget(controls, content_area[1]);
+}
+
+pub fn render_signal_modal(frame: &mut Frame, pending: &PendingSignal) {
+    let question = tf("Send {}?", &[&pending.signal.as_str()]);
+    let target = format!("{} ({})", pending.name, pending.pid);
+    let min_width = 40
+        .max(i18n::width(&question) + i18n::width(t(" Y/N")) + 6)
+        .max(i18n::width(&target) + 6);
+    let modal_area = components::center_rect_min(30, 15, min_width, 8, frame.area());
+
+    frame.render_widget(Clear, modal_area);
+
+    let modal_block = Block::default()
+        .title(
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(
+                    format!("⚡ {}", pending.signal.as_str()),
+                    Style::default()
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" "),
+            ])
+            .alignment(Alignment::Center),
+        )
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Red))
+        .style(Style::default().bg(Color::Rgb(30, 30, 40)));
+
+    frame.render_widget(modal_block, modal_area);
+
+    let content_area = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(modal_area);
+
+    let target = Paragraph::new(
+        Line::from(Span::styled(target, Style::default().fg(Color::White)))
+            .alignment(Alignment::Center),
+    );
+
+    let question = Paragraph::new(
+        Line::from(vec![
+            Span::styled(
+                question,
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                t(" Y/N"),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+        ])
+        .alignment(Alignment::Center),
+    );
+
+    let controls = Paragraph::new(Line::from(t("ESC (Cancel)")).alignment(Alignment::Center));
+
+    frame.render_widget(target, content_area[0]);
+    frame.render_widget(question, content_area[1]);
+    frame.render_widget(controls, content_area[2]);
+}
@@ -0,0 +1,192 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::types::CpuInfo;
+
+/// Rows the per-core grid may take at most; beyond that cells get narrower,
+/// and what still does not fit is summed up in the last cell.
+const MAX_ROWS: usize = 4;
+const BAR_WIDTH: usize = 8;
+/// `▕████████▏ 100%` after the label, plus the gap to the next cell.
+const WIDE_CELL: usize = 1 + 1 + BAR_WIDTH + 1 + 4 + 3;
+/// `100%` after the label, plus the gap to the next cell.
+const NARROW_CELL: usize = 1 + 4 + 1;
+
+/// Layout of the per-core grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GridShape {
+    columns: usize,
+    rows: usize,
+    wide: bool,
+    /// Digits of the highest core number.
+    label_width: usize,
+    /// Cores with a cell of their own; the rest share a "+N" cell.
+    shown: usize,
+}
+
+/// Layout of the per-core grid for `cores` cores in `width` columns.
+fn grid_shape(cores: usize, width: u16) -> GridShape {
+    let width = usize::from(width);
+    let label_width = cores.saturating_sub(1).to_string().len().max(2);
+
+    let columns = (width / (label_width + WIDE_CELL)).max(1);
+    let rows = cores.div_ceil(columns);
+    if rows <= MAX_ROWS {
+        return GridShape {
+            columns,
+            rows,
+            wide: true,
+            label_width,
+            shown: cores,
+        };
+    }
+
+    let columns = (width / (label_width + NARROW_CELL)).max(1);
+    let rows = cores.div_ceil(columns).min(MAX_ROWS);
+    let capacity = columns * rows;
+    GridShape {
+        columns,
+        rows,
+        wide: false,
+        label_width,
+        shown: if cores > capacity {
+            capacity - 1
+        } else {
+            cores
+        },
+    }
+}
+
+/// Height the per-core grid needs for `cores` cores in `width` columns.
+pub fn grid_height(cores: usize, width: u16) -> u16 {
+    u16::try_from(grid_shape(cores, width).rows).unwrap_or(u16::MAX)
+}
+
+pub fn render_core_grid(frame: &mut Frame, area: Rect, cpu: &CpuInfo) {
+    let shape = grid_shape(cpu.cores.len(), area.width);
+
+    let (shown, hidden) = cpu.cores.split_at(shape.shown);
+    let mut cells: Vec<Vec<Span>> = shown
+        .iter()
+        .enumerate()
+        .map(|(index, usage)| core_cell(&index.to_string(), *usage, &shape))
+        .collect();
+    if !hidden.is_empty() {
+        let average = hidden.iter().sum::<f32>() / hidden.len() as f32;
+        cells.push(core_cell(&format!("+{}", hidden.len()), average, &shape));
+    }
+
+    let lines: Vec<Line> = cells
+        .chunks(shape.columns)
+        .map(|row| Line::from(row.concat()))
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Cell for one core, or for the average of the cores that did not fit when
+/// `label` is `+N`.
+fn core_cell(label: &str, usage: f32, shape: &GridShape) -> Vec<Span<'static>> {
+    let label = Span::styled(
+        format!("{label:>width$} ", width = shape.label_width),
+        Style::default().fg(Color::DarkGray),
+    );
+    let value = Span::styled(
+        format!("{usage:>3.0}%"),
+        Style::default().fg(usage_color(usage)),
+    );
+
+    if shape.wide {
+        vec![
+            label,
+            Span::styled("▕", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                bar(usage, BAR_WIDTH),
+                Style::default().fg(usage_color(usage)),
+            ),
+            Span::styled("▏", Style::default().fg(Color::DarkGray)),
+            value,
+            Span::raw("   "),
+        ]
+    } else {
+        vec![label, value, Span::raw(" ")]
+    }
+}
+
+pub fn usage_color(usage: f32) -> Color {
+    if usage >= 85.0 {
+        Color::Red
+    } else if usage >= 50.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+/// Horizontal bar of `width` cells with eighth-block resolution.
+fn bar(percent: f32, width: usize) -> String {
+    const PARTIAL: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+    let eighths = (percent.clamp(0.0, 100.0) / 100.0 * (width * 8) as f32).round() as usize;
+    let full = eighths / 8;
+    let mut bar = "█".repeat(full);
+    if full < width {
+        bar.push(PARTIAL[eighths % 8]);
+        bar.push_str(&" ".repeat(width - full - 1));
+    }
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_resolution() {
+        assert_eq!(bar(0.0, 4), "    ");
+        assert_eq!(bar(50.0, 4), "██  ");
+        assert_eq!(bar(100.0, 4), "████");
+        assert_eq!(bar(12.5 / 4.0, 4), "▏   ");
+    }
+
+    #[test]
+    fn test_grid_switches_to_narrow_cells() {
+        let shape = grid_shape(8, 100);
+        assert_eq!((shape.columns, shape.rows, shape.wide), (5, 2, true));
+        assert_eq!(shape.shown, 8);
+        let shape = grid_shape(40, 100);
+        assert_eq!((shape.columns, shape.rows, shape.wide), (12, 4, false));
+        assert_eq!(shape.shown, 40);
+        assert_eq!(grid_height(0, 100), 0);
+    }
+
+    #[test]
+    fn test_grid_sums_up_cores_that_do_not_fit() {
+        // 3-digit labels: 8 narrow cells of 9 columns per row.
+        let shape = grid_shape(128, 80);
+        assert_eq!(shape.label_width, 3);
+        assert_eq!((shape.columns, shape.rows, shape.wide), (8, 4, false));
+        assert_eq!(shape.shown, 31);
+
+        let cpu = CpuInfo {
+            cores: (0..128).map(|i| if i < 31 { 0.0 } else { 50.0 }).collect(),
+            ..CpuInfo::default()
+        };
+        let backend = ratatui::backend::TestBackend::new(80, 4);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_core_grid(frame, frame.area(), &cpu))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let last_row: String = (0..80)
+            .map(|x| buffer[(x, 3)].symbol().to_string())
+            .collect();
+        assert!(last_row.starts_with(" 24   0%"), "{last_row:?}");
+        assert!(last_row.contains(" +97  50%"), "{last_row:?}");
+    }
+}
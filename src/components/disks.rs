@@ -0,0 +1,130 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    i18n::{self, t},
+    types::{DiskInfo, DISK_WARN_PERCENT},
+    units::{format_bytes, format_rate},
+};
+
+const GAUGE_WIDTH: usize = 10;
+
+pub fn render_disks(frame: &mut Frame, area: Rect, disks: &[DiskInfo], selected: usize) {
+    let title = Line::from(vec![Span::raw(t("[== DISKS ==]"))])
+        .bold()
+        .centered();
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
+        .title(title);
+
+    if disks.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                t("No mounted filesystems reported"),
+                Style::default().fg(Color::DarkGray),
+            )))
+            .centered()
+            .block(block),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "Mount", "Device", "FS", "Size", "Used", "Avail", "Use%", "", "Read", "Write",
+        ]
+        .map(|title| Cell::from(t(title)).style(Style::default().fg(Color::DarkGray).bold())),
+    );
+
+    let rows = disks.iter().map(|disk| {
+        let percent = disk.usage_percent();
+        let color = usage_color(percent);
+        let mount = if disk.is_removable {
+            format!("{} ⏏", disk.mount_point)
+        } else {
+            disk.mount_point.clone()
+        };
+
+        Row::new(vec![
+            Cell::from(mount),
+            Cell::from(disk.name.clone()).style(Style::default().fg(Color::Gray)),
+            Cell::from(disk.file_system.clone()).style(Style::default().fg(Color::Gray)),
+            Cell::from(format_bytes(disk.total)),
+            Cell::from(format_bytes(disk.used)),
+            Cell::from(format_bytes(disk.available)),
+            Cell::from(format!("{percent:>5.1}%")).style(Style::default().fg(color)),
+            Cell::from(gauge(percent)).style(Style::default().fg(color)),
+            Cell::from(format_rate(disk.read_rate)),
+            Cell::from(format_rate(disk.write_rate)),
+        ])
+        .style(if disk.is_nearly_full() {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        })
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(12),
+            Constraint::Max(16),
+            Constraint::Length(i18n::fit("FS", 7)),
+            Constraint::Length(i18n::fit("Size", 8)),
+            Constraint::Length(i18n::fit("Used", 8)),
+            Constraint::Length(i18n::fit("Avail", 8)),
+            Constraint::Length(i18n::fit("Use%", 6)),
+            Constraint::Length(GAUGE_WIDTH as u16),
+            Constraint::Length(i18n::fit("Read", 10)),
+            Constraint::Length(i18n::fit("Write", 10)),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .row_highlight_style(Style::default().bg(Color::Rgb(60, 60, 60)));
+
+    let mut state = TableState::default().with_selected(Some(selected.min(disks.len() - 1)));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn usage_color(percent: f64) -> Color {
+    if percent >= DISK_WARN_PERCENT {
+        Color::Red
+    } else if percent >= DISK_WARN_PERCENT - 15.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+fn gauge(percent: f64) -> String {
+    let filled = ((percent / 100.0) * GAUGE_WIDTH as f64).round() as usize;
+    let filled = filled.min(GAUGE_WIDTH);
+    format!("{}{}", "■".repeat(filled), "·".repeat(GAUGE_WIDTH - filled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauge_is_clamped_to_width() {
+        assert_eq!(gauge(0.0), "··········");
+        assert_eq!(gauge(50.0), "■■■■■·····");
+        assert_eq!(gauge(120.0), "■■■■■■■■■■");
+    }
+
+    #[test]
+    fn test_usage_color_thresholds() {
+        assert_eq!(usage_color(10.0), Color::Green);
+        assert_eq!(usage_color(80.0), Color::Yellow);
+        assert_eq!(usage_color(DISK_WARN_PERCENT), Color::Red);
+    }
+}
@@ -6,10 +6,14 @@ use ratatui::{
     Frame,
 };
 
-use crate::components;
+use crate::{
+    components,
+    i18n::{self, t},
+};
 
 pub fn render_quit_modal(frame: &mut Frame) {
-    let modal_area = components::center_rect(30, 15, frame.area());
+    let min_width = 30.max(i18n::width(t("Are you sure?")) + i18n::width(t(" Y/N")) + 6);
+    let modal_area = components::center_rect_min(30, 15, min_width, 7, frame.area());
 
     frame.render_widget(Clear, modal_area);
 
@@ -18,7 +22,7 @@ pub fn render_quit_modal(frame: &mut Frame) {
             Line::from(vec![
                 Span::raw(" "),
                 Span::styled(
-                    "⚠ Exit",
+                    t("⚠ Exit"),
                     Style::default()
                         .fg(Color::Gray)
                         .add_modifier(Modifier::BOLD),
@@ -42,13 +46,13 @@ pub fn render_quit_modal(frame: &mut Frame) {
 
     let question = Paragraph::new(vec![Line::from(vec![
         Span::styled(
-            "Are you sure?",
+            t("Are you sure?"),
             Style::default()
                 .fg(Color::Gray)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(
-            " Y/N",
+            t(" Y/N"),
             Style::default()
                 .fg(Color::White)
                 .add_modifier(Modifier::ITALIC),
@@ -57,7 +61,7 @@ pub fn render_quit_modal(frame: &mut Frame) {
     .alignment(Alignment::Center)])
     .style(Style::default().bg(Color::Rgb(30, 30, 40)));
 
-    let controls = Paragraph::new(Line::from("ESC (Cancel)").alignment(Alignment::Center))
+    let controls = Paragraph::new(Line::from(t("ESC (Cancel)")).alignment(Alignment::Center))
         .style(Style::default().bg(Color::Rgb(30, 30, 40)));
 
     frame.render_widget(question, content_area[0]);
@@ -6,46 +6,9 @@ use ratatui::{
     Frame,
 };
 
-use crate::components;
+use crate::{components, i18n::t};
 
 pub fn render_help_modal(frame: &mut Frame) {
-    let modal_area = components::center_rect(40, 50, frame.area());
-
-    // Clear the area to avoid overlapping
-    frame.render_widget(Clear, modal_area);
-
-    let modal_block = Block::default()
-        .title(
-            Line::from(vec![
-                Span::styled(
-                    " HELP ",
-                    Style::default()
-                        .fg(Color::LightBlue)
-                        .bg(Color::Rgb(30, 30, 40))
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::raw(" "),
-            ])
-            .alignment(Alignment::Center),
-        )
-        .borders(Borders::ALL)
-        .border_type(ratatui::widgets::BorderType::Double)
-        .border_style(Style::default().fg(Color::DarkGray))
-        .style(Style::default().bg(Color::Rgb(25, 25, 35)));
-
-    frame.render_widget(modal_block, modal_area);
-
-    let content_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .horizontal_margin(2)
-        .vertical_margin(1)
-        .constraints([
-            Constraint::Length(2), // Header
-            Constraint::Min(1),    // Content
-            Constraint::Length(2), // Footer
-        ])
-        .split(modal_area);
-
     // Define color styles
     let category_style = Style::default()
         .fg(Color::Cyan)
@@ -59,68 +22,251 @@ pub fn render_help_modal(frame: &mut Frame) {
 
     let help_content = vec![
         // Navigation section
-        Line::from(vec![Span::styled(" NAVIGATION ", category_style)]),
+        Line::from(vec![Span::styled(t(" NAVIGATION "), category_style)]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("k", key_style),
-            Span::styled(" - Move up in list", description_style),
+            Span::styled(t(" - Move up in list"), description_style),
         ]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("j", key_style),
-            Span::styled(" - Move down in list", description_style),
+            Span::styled(t(" - Move down in list"), description_style),
         ]),
         Line::from(""),
         // Task Actions section
-        Line::from(vec![Span::styled(" TASK ACTIONS ", category_style)]),
+        Line::from(vec![Span::styled(t(" TASK ACTIONS "), category_style)]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("A", key_style),
-            Span::styled(" - Add new task", description_style),
+            Span::styled(t(" - Add new task"), description_style),
         ]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("D", key_style),
-            Span::styled(" - Delete selected task", description_style),
+            Span::styled(t(" - Delete selected/marked tasks"), description_style),
         ]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("T", key_style),
-            Span::styled(" - Toggle task status", description_style),
+            Span::styled(t(" - Toggle task status"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("E", key_style),
+            Span::styled(t(" - Edit task in $VISUAL / $EDITOR"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("PgUp / PgDn", key_style),
+            Span::styled(t(" - Scroll the task description"), description_style),
+        ]),
+        Line::from(""),
+        // Multi-select section
+        Line::from(vec![Span::styled(t(" MULTI-SELECT "), category_style)]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("Space / V", key_style),
+            Span::styled(t(" - Mark task / mark a range"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("1-4", key_style),
+            Span::styled(
+                t(" - Set status Todo/Active/Done/Cancelled"),
+                description_style,
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("# / X", key_style),
+            Span::styled(t(" - Tag / archive marked tasks"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("Esc", key_style),
+            Span::styled(t(" - Clear marks"), description_style),
         ]),
         Line::from(""),
         // Input Modal section
-        Line::from(vec![Span::styled(" INPUT MODAL ", category_style)]),
+        Line::from(vec![Span::styled(t(" INPUT MODAL "), category_style)]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("Ctrl+S", key_style),
+            Span::styled(t(" - Save task (Enter in title field)"), description_style),
+        ]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("Enter", key_style),
-            Span::styled(" - Save task", description_style),
+            Span::styled(
+                t(" - New line in description (Markdown)"),
+                description_style,
+            ),
         ]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("Tab", key_style),
-            Span::styled(" - Switch between title/message fields", description_style),
+            Span::styled(
+                t(" - Switch between title/message fields"),
+                description_style,
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("←/→ Home/End", key_style),
+            Span::styled(t(" - Move cursor (Alt+B/F by word)"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("Ctrl+W / Ctrl+U", key_style),
+            Span::styled(t(" - Delete word / to line start"), description_style),
         ]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("Esc", key_style),
-            Span::styled(" - Cancel and close modal", description_style),
+            Span::styled(t(" - Cancel and close modal"), description_style),
         ]),
         Line::from(""),
         // System Controls section
-        Line::from(vec![Span::styled(" SYSTEM CONTROLS ", category_style)]),
+        Line::from(vec![Span::styled(t(" SYSTEM CONTROLS "), category_style)]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("Q / Ctrl+C ", key_style),
-            Span::styled(" - Show quit confirmation", description_style),
+            Span::styled(t(" - Show quit confirmation"), description_style),
         ]),
         Line::from(vec![
             Span::raw("   "),
             Span::styled("?", key_style),
-            Span::styled(" - Show this help menu", description_style),
+            Span::styled(t(" - Show this help menu"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("s", key_style),
+            Span::styled(t(" - Toggle CPU/RAM/swap history"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("i", key_style),
+            Span::styled(
+                t(" - Toggle host details (OS, kernel, boot time, load)"),
+                description_style,
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("w / W", key_style),
+            Span::styled(
+                t(" - Toggle weather panel / hourly or daily forecast"),
+                description_style,
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("L", key_style),
+            Span::styled(t(" - Switch to the next saved location"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("r", key_style),
+            Span::styled(t(" - Refresh weather now"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("Tab / Shift+Tab", key_style),
+            Span::styled(t(" - Switch between views"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("j / k", key_style),
+            Span::styled(t(" - Select a row in the system views"), description_style),
+        ]),
+        Line::from(""),
+        // Process view section
+        Line::from(vec![Span::styled(t(" PROCESSES "), category_style)]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("/", key_style),
+            Span::styled(
+                t(" - Filter by name, user, command or PID"),
+                description_style,
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("< / > / I", key_style),
+            Span::styled(t(" - Change sort column / invert order"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("t", key_style),
+            Span::styled(t(" - Toggle tree mode"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("x / X", key_style),
+            Span::styled(
+                t(" - Send SIGTERM / SIGKILL (asks first)"),
+                description_style,
+            ),
+        ]),
+        Line::from(""),
+        // Metrics history section
+        Line::from(vec![Span::styled(t(" HISTORY "), category_style)]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("j / k", key_style),
+            Span::styled(t(" - Choose the charted metric"), description_style),
+        ]),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled("h / l", key_style),
+            Span::styled(
+                t(" - Shorter / longer time range (1h to 90d)"),
+                description_style,
+            ),
         ]),
     ];
 
+    // The content takes 70% of the modal; widen it for longer translations.
+    let content_width = help_content.iter().map(Line::width).max().unwrap_or(0);
+    let min_width = u16::try_from(content_width * 10 / 7 + 6).unwrap_or(u16::MAX);
+    let modal_area = components::center_rect_min(40, 70, min_width.max(50), 55, frame.area());
+
+    // Clear the area to avoid overlapping
+    frame.render_widget(Clear, modal_area);
+
+    let modal_block = Block::default()
+        .title(
+            Line::from(vec![
+                Span::styled(
+                    t(" HELP "),
+                    Style::default()
+                        .fg(Color::LightBlue)
+                        .bg(Color::Rgb(30, 30, 40))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" "),
+            ])
+            .alignment(Alignment::Center),
+        )
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Double)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().bg(Color::Rgb(25, 25, 35)));
+
+    frame.render_widget(modal_block, modal_area);
+
+    let content_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .horizontal_margin(2)
+        .vertical_margin(1)
+        .constraints([
+            Constraint::Length(2), // Header
+            Constraint::Min(1),    // Content
+            Constraint::Length(2), // Footer
+        ])
+        .split(modal_area);
+
     let help_text = Paragraph::new(help_content)
         .style(Style::default().bg(Color::Rgb(25, 25, 35)))
         .alignment(Alignment::Left)
@@ -135,7 +281,7 @@ pub fn render_help_modal(frame: &mut Frame) {
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" to close help", Style::default().fg(Color::Gray)),
+            Span::styled(t(" to close help"), Style::default().fg(Color::Gray)),
             Span::raw(" "),
         ])
         .alignment(Alignment::Center),
@@ -0,0 +1,103 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    components::{
+        cpu::usage_color,
+        sparkline::{self, SeriesChart},
+    },
+    i18n::{self, t},
+    system::history::{MetricsHistory, RingBuffer},
+};
+
+/// Rows taken by the expanded history section of the system panel.
+pub const HISTORY_HEIGHT: u16 = 6;
+const LABEL_WIDTH: u16 = 14;
+
+pub fn render_history(frame: &mut Frame, area: Rect, history: &MetricsHistory, swap_total: f64) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2); 3])
+        .split(area);
+    let labels = Labels::new();
+
+    render_series(frame, rows[0], &labels, "CPU", &history.cpu, Color::Cyan);
+    render_series(
+        frame,
+        rows[1],
+        &labels,
+        "RAM",
+        &history.memory,
+        Color::Magenta,
+    );
+    if swap_total > 0.0 {
+        render_series(frame, rows[2], &labels, "Swap", &history.swap, Color::Blue);
+    } else {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!("{:<width$}{}", t("Swap"), t("none"), width = labels.name),
+                Style::default().fg(Color::DarkGray),
+            )),
+            rows[2],
+        );
+    }
+}
+
+/// Widths of the label column, grown to fit translated names.
+struct Labels {
+    name: usize,
+    column: u16,
+}
+
+impl Labels {
+    fn new() -> Self {
+        let name = i18n::max_width(&["CPU", "RAM", "Swap"]).max(4) + 1;
+        let peak = i18n::width(t("peak")) + 7;
+        Self {
+            name: name.into(),
+            column: LABEL_WIDTH.max(name + 7).max(peak),
+        }
+    }
+}
+
+fn render_series(
+    frame: &mut Frame,
+    area: Rect,
+    labels: &Labels,
+    name: &'static str,
+    series: &RingBuffer<f64>,
+    color: Color,
+) {
+    let latest = series.latest().unwrap_or_default();
+    let peak = series.iter().fold(0.0_f64, f64::max);
+    let label = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{:<width$}", t(name), width = labels.name),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                format!("{latest:>5.1}%"),
+                Style::default().fg(usage_color(latest as f32)),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!("{} {peak:>5.1}%", t("peak")),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    let chart = SeriesChart {
+        label,
+        label_width: labels.column,
+        color,
+        max: 1000,
+    };
+    sparkline::render_series(frame, area, chart, series, |percent| {
+        (percent.clamp(0.0, 100.0) * 10.0).round() as u64
+    });
+}
@@ -0,0 +1,62 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::{
+    components::ram::load_color,
+    i18n::{t, tf},
+    types::SystemData,
+    units::{format_datetime, format_duration},
+};
+
+/// Rows taken by the expanded host details of the system panel.
+pub const HOST_DETAILS_HEIGHT: u16 = 3;
+
+pub fn render_host_details(frame: &mut Frame, area: Rect, data: &SystemData) {
+    let label = Style::default().fg(Color::DarkGray);
+    let value = Style::default().fg(Color::White);
+    let host = &data.host;
+    let booted = host
+        .boot_time_local()
+        .map(|time| format_datetime(&time))
+        .unwrap_or_else(|| t("unknown").to_string());
+    let load = |load: f64| {
+        Span::styled(
+            format!("{load:.2}"),
+            Style::default().fg(load_color(load, data.cpu.len)),
+        )
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(t("Host: "), label),
+            Span::styled(host.hostname.clone(), value),
+            Span::styled(t("  OS: "), label),
+            Span::styled(host.os_name.clone(), value),
+            Span::styled(t("  Kernel: "), label),
+            Span::styled(host.kernel.clone(), value),
+        ]),
+        Line::from(vec![
+            Span::styled(t("Booted: "), label),
+            Span::styled(booted, value),
+            Span::styled(t("  Uptime: "), label),
+            Span::styled(format_duration(host.uptime), value),
+            Span::styled(t("  Load 1/5/15m: "), label),
+            load(host.load_one),
+            Span::styled(" / ", label),
+            load(host.load_five),
+            Span::styled(" / ", label),
+            load(host.load_fifteen),
+            Span::styled(tf(" ({} cores)", &[&data.cpu.len]), label),
+        ]),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(50, 50, 50)));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
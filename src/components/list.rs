@@ -6,58 +6,119 @@ use ratatui::{
     Frame,
 };
 
-use crate::types::ListState;
+use crate::{
+    components::LayoutMode,
+    i18n::{self, t, tf},
+    types::{ListState, Status},
+};
 
-pub fn render_list(frame: &mut Frame, area: ratatui::layout::Rect, state: &ListState) {
-    let title = Line::from(vec![Span::raw("[== TODOS PANEL ==]")])
-        .bold()
-        .centered();
+pub fn render_list(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    state: &ListState,
+    mode: LayoutMode,
+) {
+    let mut title_spans = vec![Span::raw(t("[== TODOS PANEL ==]"))];
+    if state.visual_anchor.is_some() {
+        title_spans.push(Span::styled(
+            t(" VISUAL"),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    let marked = state.target_ids().len();
+    if state.has_marks() {
+        title_spans.push(Span::styled(
+            tf(" {} marked", &[&marked]),
+            Style::default().fg(Color::LightGreen),
+        ));
+    }
+    let title = Line::from(title_spans).bold().centered();
 
     let items: Vec<ListItem> = if state.items.is_empty() {
         vec![ListItem::new(
             Line::from(Span::styled(
-                "Add some =]",
+                t("Add some =]"),
                 Style::default().fg(Color::DarkGray),
             ))
             .centered(),
         )]
     } else {
+        // Statuses are padded to the longest translated one so titles line up.
+        let status_width = Status::ALL
+            .iter()
+            .map(|status| i18n::width(t(status.as_str())))
+            .max()
+            .unwrap_or(0) as usize
+            + 2;
         state
             .items
             .iter()
             .enumerate()
             .map(|(i, item)| {
-                let status_str = format!("[{:?}]", item.status);
-                let padded_status = format!("{:12}", status_str);
+                let status_str = format!("[{}]", t(item.status.as_str()));
+                let padded_status = format!("{status_str:status_width$}");
+                let priority = match item.priority {
+                    crate::types::Priority::High => Span::styled(
+                        "! ",
+                        Style::default()
+                            .fg(item.priority.get_color())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    _ => Span::raw("  "),
+                };
+                let is_marked = state.is_marked(i);
+                let mark = if is_marked {
+                    Span::styled("✓", Style::default().fg(Color::LightGreen).bold())
+                } else {
+                    Span::raw(" ")
+                };
+                let tags = Span::styled(
+                    item.tags
+                        .iter()
+                        .map(|tag| format!("#{tag}"))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    Style::default().fg(Color::Rgb(110, 110, 140)),
+                );
 
                 let content = if state.selected == Some(i) {
                     Line::from(vec![
+                        mark,
                         Span::styled(
-                            format!("  ● {} ", padded_status),
+                            format!(" ● {} ", padded_status),
                             Style::default()
                                 .fg(Color::Rgb(255, 203, 164))
                                 .add_modifier(Modifier::BOLD),
                         ),
+                        priority,
                         Span::styled(
                             format!("{} ", item.title),
                             Style::default()
                                 .fg(Color::Rgb(255, 203, 164))
                                 .add_modifier(Modifier::ITALIC),
                         ),
+                        tags,
                     ])
                 } else {
                     Line::from(vec![
+                        mark,
                         Span::styled(
-                            format!("  ○ {} ", padded_status),
+                            format!(" ○ {} ", padded_status),
                             Style::default().fg(match item.status {
                                 crate::types::Status::Done => Color::Rgb(80, 80, 80),
                                 _ => Color::DarkGray,
                             }),
                         ),
+                        priority,
                         Span::styled(format!("{} ", item.title), Style::default().fg(Color::Gray)),
+                        tags,
                     ])
                 };
-                ListItem::new(content)
+                if is_marked {
+                    ListItem::new(content).style(Style::default().bg(Color::Rgb(35, 45, 35)))
+                } else {
+                    ListItem::new(content)
+                }
             })
             .collect()
     };
@@ -66,7 +127,10 @@ pub fn render_list(frame: &mut Frame, area: ratatui::layout::Rect, state: &ListS
         .block(
             Block::default()
                 .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
-                .borders(Borders::RIGHT)
+                .borders(match mode {
+                    LayoutMode::Wide => Borders::RIGHT,
+                    _ => Borders::BOTTOM,
+                })
                 .title(title),
         )
         .highlight_style(
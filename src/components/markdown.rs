@@ -0,0 +1,353 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Render a small Markdown subset into wrapped lines of `width` columns.
+///
+/// Supported: `#` headings, `**bold**`, `*italic*`/`_italic_`, `` `code` ``,
+/// bullet lists (`-`, `*`, `+`, with `[ ]`/`[x]` checkboxes), fenced code
+/// blocks and paragraphs, where consecutive lines are joined and re-wrapped.
+pub fn render_markdown(text: &str, width: usize) -> Vec<Line<'static>> {
+    let width = width.max(8);
+    let mut lines = Vec::new();
+    let mut paragraph = String::new();
+    let mut in_code_block = false;
+
+    for raw in text.lines() {
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("```") {
+            flush_paragraph(&mut paragraph, &mut lines, width);
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(Line::from(Span::styled(format!("  {raw}"), code_style())));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut lines, width);
+            lines.push(Line::default());
+        } else if let Some((level, heading)) = parse_heading(trimmed) {
+            flush_paragraph(&mut paragraph, &mut lines, width);
+            let tokens = tokenize(&parse_inline(heading, heading_style(level)));
+            lines.extend(wrap_tokens(&tokens, width, Vec::new(), 0));
+        } else if let Some((marker, item)) = parse_bullet(trimmed) {
+            flush_paragraph(&mut paragraph, &mut lines, width);
+            let indent = (raw.len() - trimmed.len()) / 2 * 2;
+            let prefix = vec![
+                Span::raw(" ".repeat(indent)),
+                Span::styled(marker, Style::default().fg(Color::Cyan)),
+            ];
+            let hang = indent + marker.width();
+            let tokens = tokenize(&parse_inline(item, Style::default()));
+            lines.extend(wrap_tokens(&tokens, width, prefix, hang));
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(trimmed);
+        }
+    }
+    flush_paragraph(&mut paragraph, &mut lines, width);
+
+    while lines.last().is_some_and(|line| line.spans.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+fn flush_paragraph(paragraph: &mut String, lines: &mut Vec<Line<'static>>, width: usize) {
+    if paragraph.is_empty() {
+        return;
+    }
+    let tokens = tokenize(&parse_inline(paragraph, Style::default()));
+    lines.extend(wrap_tokens(&tokens, width, Vec::new(), 0));
+    paragraph.clear();
+}
+
+fn code_style() -> Style {
+    Style::default()
+        .fg(Color::LightYellow)
+        .bg(Color::Rgb(40, 40, 50))
+}
+
+fn heading_style(level: usize) -> Style {
+    let style = Style::default().add_modifier(Modifier::BOLD);
+    match level {
+        1 => style.fg(Color::Cyan).add_modifier(Modifier::UNDERLINED),
+        2 => style.fg(Color::Cyan),
+        _ => style.fg(Color::LightBlue),
+    }
+}
+
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..]
+        .strip_prefix(' ')
+        .map(|heading| (level, heading.trim()))
+}
+
+fn parse_bullet(line: &str) -> Option<(&'static str, &str)> {
+    let item = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))?;
+
+    if let Some(rest) = item.strip_prefix("[ ] ") {
+        Some(("☐ ", rest))
+    } else if let Some(rest) = item
+        .strip_prefix("[x] ")
+        .or_else(|| item.strip_prefix("[X] "))
+    {
+        Some(("☑ ", rest))
+    } else {
+        Some(("• ", item))
+    }
+}
+
+/// Split a line into styled segments according to the inline markers.
+/// Markers without a matching closing marker are kept as literal text.
+fn parse_inline(text: &str, base: Style) -> Vec<(String, Style)> {
+    let mut segments: Vec<(String, Style)> = Vec::new();
+    let mut current = String::new();
+    let mut bold = false;
+    let mut italic: Option<char> = None;
+    let mut rest = text;
+    let mut prev: Option<char> = None;
+
+    let style_for = |bold: bool, italic: bool| {
+        let mut style = base;
+        if bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if italic {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        style
+    };
+
+    while let Some(c) = rest.chars().next() {
+        let style = style_for(bold, italic.is_some());
+
+        if c == '`' {
+            if let Some(end) = rest[1..].find('`') {
+                push_segment(&mut segments, &mut current, style);
+                segments.push((rest[1..=end].to_owned(), code_style()));
+                rest = &rest[end + 2..];
+                prev = Some('`');
+                continue;
+            }
+        } else if rest.starts_with("**") && (bold || rest[2..].contains("**")) {
+            push_segment(&mut segments, &mut current, style);
+            bold = !bold;
+            rest = &rest[2..];
+            prev = Some('*');
+            continue;
+        } else if c == '*' || c == '_' {
+            let next = rest[1..].chars().next();
+            let closes = italic == Some(c) && !next.is_some_and(char::is_alphanumeric);
+            let opens = italic.is_none()
+                && !prev.is_some_and(char::is_alphanumeric)
+                && next.is_some_and(|n| !n.is_whitespace())
+                && rest[1..].contains(c);
+            if opens || closes {
+                push_segment(&mut segments, &mut current, style);
+                italic = if opens { Some(c) } else { None };
+                rest = &rest[1..];
+                prev = Some(c);
+                continue;
+            }
+        }
+
+        current.push(c);
+        rest = &rest[c.len_utf8()..];
+        prev = Some(c);
+    }
+    push_segment(
+        &mut segments,
+        &mut current,
+        style_for(bold, italic.is_some()),
+    );
+    segments
+}
+
+fn push_segment(segments: &mut Vec<(String, Style)>, current: &mut String, style: Style) {
+    if !current.is_empty() {
+        segments.push((std::mem::take(current), style));
+    }
+}
+
+/// A word (or part of one) with the information whether whitespace precedes it.
+#[derive(Debug, PartialEq)]
+struct Token {
+    text: String,
+    style: Style,
+    space_before: bool,
+}
+
+fn tokenize(segments: &[(String, Style)]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut pending_space = false;
+
+    for (text, style) in segments {
+        let mut start = None;
+        for (i, c) in text.char_indices() {
+            if c.is_whitespace() {
+                if let Some(s) = start.take() {
+                    tokens.push(Token {
+                        text: text[s..i].to_owned(),
+                        style: *style,
+                        space_before: pending_space,
+                    });
+                }
+                pending_space = true;
+            } else if start.is_none() {
+                start = Some(i);
+            }
+        }
+        if let Some(s) = start {
+            tokens.push(Token {
+                text: text[s..].to_owned(),
+                style: *style,
+                space_before: pending_space,
+            });
+            pending_space = false;
+        }
+    }
+    tokens
+}
+
+/// Greedy word wrap. The first line starts with `prefix`, continuation lines
+/// are indented by `hang` columns. Words wider than a line are split.
+fn wrap_tokens(
+    tokens: &[Token],
+    width: usize,
+    prefix: Vec<Span<'static>>,
+    hang: usize,
+) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut spans = prefix;
+    let mut column = hang;
+    let mut line_empty = true;
+
+    for token in tokens {
+        let mut text = token.text.as_str();
+        let space = usize::from(token.space_before && !line_empty);
+
+        if !line_empty && column + space + text.width() > width {
+            lines.push(Line::from(std::mem::take(&mut spans)));
+            spans.push(Span::raw(" ".repeat(hang)));
+            column = hang;
+            line_empty = true;
+        } else if space == 1 {
+            spans.push(Span::raw(" "));
+            column += 1;
+        }
+
+        while column + text.width() > width {
+            let available = width.saturating_sub(column).max(1);
+            let split = split_at_width(text, available);
+            spans.push(Span::styled(text[..split].to_owned(), token.style));
+            lines.push(Line::from(std::mem::take(&mut spans)));
+            spans.push(Span::raw(" ".repeat(hang)));
+            column = hang;
+            text = &text[split..];
+        }
+
+        if !text.is_empty() {
+            spans.push(Span::styled(text.to_owned(), token.style));
+            column += text.width();
+            line_empty = false;
+        }
+    }
+
+    if !line_empty || lines.is_empty() {
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Byte offset of the longest grapheme prefix of `text` fitting in `width`
+/// columns (at least one grapheme, so wrapping always makes progress).
+fn split_at_width(text: &str, width: usize) -> usize {
+    let mut used = 0;
+    for (i, grapheme) in text.grapheme_indices(true) {
+        used += grapheme.width();
+        if used > width {
+            return if i == 0 { grapheme.len() } else { i };
+        }
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_paragraph_lines_are_joined_and_wrapped() {
+        let lines = render_markdown("one two\nthree four five", 10);
+        let text: Vec<String> = lines.iter().map(plain).collect();
+        assert_eq!(text, vec!["one two", "three four", "five"]);
+    }
+
+    #[test]
+    fn test_heading_and_inline_styles() {
+        let lines = render_markdown("# Plan\nbuy **milk** and *eggs* with `code`", 80);
+        assert_eq!(plain(&lines[0]), "Plan");
+        assert!(lines[0].spans[0]
+            .style
+            .add_modifier
+            .contains(Modifier::BOLD));
+
+        let spans = &lines[1].spans;
+        let bold = spans.iter().find(|s| s.content == "milk").unwrap();
+        assert!(bold.style.add_modifier.contains(Modifier::BOLD));
+        let italic = spans.iter().find(|s| s.content == "eggs").unwrap();
+        assert!(italic.style.add_modifier.contains(Modifier::ITALIC));
+        let code = spans.iter().find(|s| s.content == "code").unwrap();
+        assert_eq!(code.style, code_style());
+    }
+
+    #[test]
+    fn test_unclosed_markers_stay_literal() {
+        let lines = render_markdown("2 * 3 and snake_case_name", 80);
+        assert_eq!(plain(&lines[0]), "2 * 3 and snake_case_name");
+        assert!(lines[0]
+            .spans
+            .iter()
+            .all(|s| !s.style.add_modifier.contains(Modifier::ITALIC)));
+    }
+
+    #[test]
+    fn test_bullets_and_checklists_hang_indent() {
+        let lines = render_markdown("- [ ] first task\n- [x] done\n- a long bullet item", 14);
+        let text: Vec<String> = lines.iter().map(plain).collect();
+        assert_eq!(
+            text,
+            vec!["☐ first task", "☑ done", "• a long", "  bullet item"]
+        );
+    }
+
+    #[test]
+    fn test_long_words_are_split() {
+        let lines = render_markdown("https://example.com/very/long", 10);
+        let text: Vec<String> = lines.iter().map(plain).collect();
+        assert_eq!(text, vec!["https://ex", "ample.com/", "very/long"]);
+    }
+}
@@ -1,21 +1,74 @@
+mod alerts;
+mod confirm;
+mod cpu;
+mod disks;
 mod exit;
 mod help;
+mod history;
+mod host;
 pub mod input;
 pub mod list;
+mod markdown;
+mod network;
+mod processes;
 mod ram;
 mod selected;
+mod sensors;
+mod sparkline;
+mod tabs;
+mod tag;
+pub mod text_input;
+mod too_small;
+mod trend;
+mod weather;
 mod welcome;
 
-pub use ram::format_sys_text;
+pub use ram::{format_sys_line, format_sys_text};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, View};
+
+/// Smallest terminal size the dashboard is rendered at.
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 12;
+/// Below this width the list and the detail panel are stacked vertically.
+pub const STACK_BREAKPOINT: u16 = 90;
+/// Below this height the system panel is collapsed into a single line.
+pub const COMPACT_HEIGHT: u16 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// Not enough room for anything but a warning.
+    TooSmall,
+    /// Stacked list/detail and a one-line system panel.
+    Compact,
+    /// Side-by-side list/detail and the full system panel.
+    Wide,
+}
+
+impl LayoutMode {
+    pub fn for_area(area: Rect) -> Self {
+        if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+            LayoutMode::TooSmall
+        } else if area.width < STACK_BREAKPOINT || area.height < COMPACT_HEIGHT {
+            LayoutMode::Compact
+        } else {
+            LayoutMode::Wide
+        }
+    }
+}
 
 pub fn render(frame: &mut Frame, app: &App) {
+    let mode = LayoutMode::for_area(frame.area());
+    if mode == LayoutMode::TooSmall {
+        too_small::render_too_small(frame);
+        return;
+    }
+
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -25,24 +78,114 @@ pub fn render(frame: &mut Frame, app: &App) {
         ])
         .split(frame.area());
 
+    let core_rows = cpu::grid_height(app.sys_data.cpu.cores.len(), layout[1].width);
+    let history_rows = if app.show_sys_history {
+        history::HISTORY_HEIGHT
+    } else {
+        0
+    };
+    let details_rows = if app.show_host_details {
+        host::HOST_DETAILS_HEIGHT
+    } else {
+        0
+    };
+    let weather_rows = if app.show_weather {
+        weather::WEATHER_PANEL_HEIGHT
+    } else {
+        0
+    };
+    let sys_height = match mode {
+        LayoutMode::Wide => 4 + core_rows + details_rows + history_rows,
+        _ => 1 + details_rows + history_rows,
+    };
+
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
+            Constraint::Length(weather_rows),
+            Constraint::Length(1),
             Constraint::Min(3),
-            Constraint::Length(3),
-            Constraint::Length(0),
+            Constraint::Length(sys_height),
         ])
         .split(layout[1]);
 
-    let main_chunks_split = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Min(40), Constraint::Min(40)])
-        .split(main_chunks[0]);
-
     welcome::render_welcome(frame, layout[0], app);
-    list::render_list(frame, main_chunks_split[0], &app.list_state);
-    selected::render_select(frame, main_chunks_split[1], &app.list_state);
-    ram::render_memory_info(frame, main_chunks[1], &app.sys_text);
+    if app.show_weather {
+        weather::render_weather(
+            frame,
+            main_chunks[0],
+            app.weather_info(),
+            app.forecast(),
+            app.forecast_mode,
+        );
+    }
+    tabs::render_tabs(frame, main_chunks[1], app.view);
+
+    let view_area = main_chunks[2];
+    match app.view {
+        View::Tasks => render_tasks(frame, view_area, app, mode),
+        View::Disks => {
+            disks::render_disks(frame, view_area, &app.sys_data.disks, app.disk_selected)
+        }
+        View::Network => network::render_network(
+            frame,
+            view_area,
+            &app.sys_data.networks,
+            &app.sys_history,
+            app.public_ip(),
+            app.network_selected,
+        ),
+        View::Processes => processes::render_processes(frame, view_area, &app.process_table),
+        View::Sensors => sensors::render_sensors(frame, view_area, &app.sys_data.sensors),
+        View::Alerts => alerts::render_alerts(frame, view_area, &app.alerts),
+        View::History => trend::render_trend(frame, view_area, &app.trend),
+    }
+
+    let sys_area = main_chunks[3];
+    match mode {
+        LayoutMode::Wide => {
+            let sys_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(4),
+                    Constraint::Length(core_rows),
+                    Constraint::Length(details_rows),
+                    Constraint::Length(history_rows),
+                ])
+                .split(sys_area);
+            ram::render_memory_info(frame, sys_chunks[0], &app.sys_text, app.notice.as_ref());
+            cpu::render_core_grid(frame, sys_chunks[1], &app.sys_data.cpu);
+            if app.show_host_details {
+                host::render_host_details(frame, sys_chunks[2], &app.sys_data);
+            }
+            history::render_history(
+                frame,
+                sys_chunks[3],
+                &app.sys_history,
+                app.sys_data.swap_total,
+            );
+        }
+        _ => {
+            let sys_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(1),
+                    Constraint::Length(details_rows),
+                    Constraint::Length(history_rows),
+                ])
+                .split(sys_area);
+            ram::render_memory_line(frame, sys_chunks[0], &app.sys_data, app.notice.as_ref());
+            if app.show_host_details {
+                host::render_host_details(frame, sys_chunks[1], &app.sys_data);
+            }
+            history::render_history(
+                frame,
+                sys_chunks[2],
+                &app.sys_history,
+                app.sys_data.swap_total,
+            );
+        }
+    }
 
     if app.show_quit_modal {
         exit::render_quit_modal(frame);
@@ -55,6 +198,40 @@ pub fn render(frame: &mut Frame, app: &App) {
     if app.show_add_modal {
         input::render_input_modal(frame, app);
     }
+
+    if let Some(ids) = &app.pending_delete {
+        confirm::render_delete_modal(frame, ids.len());
+    }
+
+    if let Some(ids) = &app.pending_archive {
+        confirm::render_archive_modal(frame, ids.len());
+    }
+
+    if let Some(pending) = &app.pending_signal {
+        confirm::render_signal_modal(frame, pending);
+    }
+
+    if let Some(tag_input) = &app.tag_input {
+        tag::render_tag_modal(frame, tag_input, app.list_state.target_ids().len());
+    }
+}
+
+fn render_tasks(frame: &mut Frame, area: Rect, app: &App, mode: LayoutMode) {
+    let chunks = match mode {
+        LayoutMode::Wide => Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area),
+        _ => Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(area),
+    };
+
+    list::render_list(frame, chunks[0], &app.list_state, mode);
+    let max_scroll =
+        selected::render_select(frame, chunks[1], &app.list_state, app.detail_scroll());
+    app.detail_max_scroll.set(max_scroll);
 }
 
 pub fn center_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -77,6 +254,27 @@ pub fn center_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Same as [`center_rect`], but never smaller than `min_width` x `min_height`
+/// (as long as the container allows it), so modals stay usable on small terminals.
+pub fn center_rect_min(
+    percent_x: u16,
+    percent_y: u16,
+    min_width: u16,
+    min_height: u16,
+    r: Rect,
+) -> Rect {
+    let rect = center_rect(percent_x, percent_y, r);
+    let width = rect.width.max(min_width).min(r.width);
+    let height = rect.height.max(min_height).min(r.height);
+
+    Rect::new(
+        r.x + (r.width - width) / 2,
+        r.y + (r.height - height) / 2,
+        width,
+        height,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +298,47 @@ mod tests {
         assert!(centered.width < container.width / 2);
         assert!(centered.height < container.height / 2);
     }
+
+    #[test]
+    fn test_center_rect_min_grows_to_minimum() {
+        let container = Rect::new(0, 0, 60, 20);
+        let centered = center_rect_min(30, 15, 40, 8, container);
+
+        assert_eq!(centered.width, 40);
+        assert_eq!(centered.height, 8);
+        assert_eq!(centered.x, 10);
+        assert_eq!(centered.y, 6);
+    }
+
+    #[test]
+    fn test_center_rect_min_clamped_to_container() {
+        let container = Rect::new(5, 5, 30, 6);
+        let centered = center_rect_min(50, 50, 40, 10, container);
+
+        assert_eq!(centered, container);
+    }
+
+    #[test]
+    fn test_layout_mode_breakpoints() {
+        assert_eq!(
+            LayoutMode::for_area(Rect::new(0, 0, 30, 40)),
+            LayoutMode::TooSmall
+        );
+        assert_eq!(
+            LayoutMode::for_area(Rect::new(0, 0, 120, 8)),
+            LayoutMode::TooSmall
+        );
+        assert_eq!(
+            LayoutMode::for_area(Rect::new(0, 0, 60, 40)),
+            LayoutMode::Compact
+        );
+        assert_eq!(
+            LayoutMode::for_area(Rect::new(0, 0, 120, 15)),
+            LayoutMode::Compact
+        );
+        assert_eq!(
+            LayoutMode::for_area(Rect::new(0, 0, 120, 40)),
+            LayoutMode::Wide
+        );
+    }
 }
@@ -0,0 +1,202 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    components::sparkline::{self, SeriesChart},
+    i18n::{self, t, tf},
+    system::history::{MetricsHistory, RingBuffer},
+    types::NetworkInfo,
+    units::{format_bytes, format_rate},
+};
+
+const LABEL_WIDTH: u16 = 16;
+const SUMMARY_HEIGHT: u16 = 1;
+const CHART_HEIGHT: u16 = 4;
+
+pub fn render_network(
+    frame: &mut Frame,
+    area: Rect,
+    networks: &[NetworkInfo],
+    history: &MetricsHistory,
+    public_ip: Option<&str>,
+    selected: usize,
+) {
+    let title = Line::from(vec![Span::raw(t("[== NETWORK ==]"))])
+        .bold()
+        .centered();
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
+        .title(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(SUMMARY_HEIGHT),
+            Constraint::Length(CHART_HEIGHT),
+            Constraint::Min(2),
+        ])
+        .split(inner);
+
+    render_summary(frame, chunks[0], networks, public_ip);
+    render_throughput(frame, chunks[1], history);
+    render_interfaces(frame, chunks[2], networks, selected);
+}
+
+fn render_summary(
+    frame: &mut Frame,
+    area: Rect,
+    networks: &[NetworkInfo],
+    public_ip: Option<&str>,
+) {
+    let label = Style::default().fg(Color::DarkGray);
+    let ip = match public_ip {
+        Some(ip) => Span::styled(ip.to_owned(), Style::default().fg(Color::Cyan)),
+        None => Span::styled(t("unknown"), label),
+    };
+    let errors: u64 = networks
+        .iter()
+        .map(|net| net.rx_errors + net.tx_errors)
+        .sum();
+    let error_style = if errors > 0 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
+    };
+
+    let line = Line::from(vec![
+        Span::styled(t(" Public IP: "), label),
+        ip,
+        Span::styled(t("   Interfaces: "), label),
+        Span::raw(networks.len().to_string()),
+        Span::styled(t("   Errors: "), label),
+        Span::styled(errors.to_string(), error_style),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn render_throughput(frame: &mut Frame, area: Rect, history: &MetricsHistory) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2); 2])
+        .split(area);
+
+    // Both directions share a scale so their heights can be compared.
+    let width = usize::from(area.width.saturating_sub(LABEL_WIDTH));
+    let max = history
+        .rx
+        .last_n(width)
+        .chain(history.tx.last_n(width))
+        .fold(1.0_f64, f64::max)
+        .ceil() as u64;
+
+    render_series(frame, rows[0], "RX", &history.rx, Color::Green, max);
+    render_series(frame, rows[1], "TX", &history.tx, Color::Blue, max);
+}
+
+fn render_series(
+    frame: &mut Frame,
+    area: Rect,
+    name: &str,
+    series: &RingBuffer<f64>,
+    color: Color,
+    max: u64,
+) {
+    let latest = series.latest().unwrap_or_default();
+    let peak = series.iter().fold(0.0_f64, f64::max);
+    let label = vec![
+        Line::from(vec![
+            Span::styled(format!(" {name:<3}"), Style::default().fg(Color::DarkGray)),
+            Span::styled(format_rate(latest), Style::default().fg(color)),
+        ]),
+        Line::from(Span::styled(
+            tf(" peak {}", &[&format_rate(peak)]),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    let chart = SeriesChart {
+        label,
+        label_width: LABEL_WIDTH,
+        color,
+        max,
+    };
+    sparkline::render_series(frame, area, chart, series, |rate| {
+        rate.max(0.0).round() as u64
+    });
+}
+
+fn render_interfaces(frame: &mut Frame, area: Rect, networks: &[NetworkInfo], selected: usize) {
+    if networks.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                t("No network interfaces reported"),
+                Style::default().fg(Color::DarkGray),
+            )))
+            .centered(),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "Interface",
+            "Addresses",
+            "RX/s",
+            "TX/s",
+            "Received",
+            "Sent",
+            "Errors",
+        ]
+        .map(|title| Cell::from(t(title)).style(Style::default().fg(Color::DarkGray).bold())),
+    );
+
+    let rows = networks.iter().map(|net| {
+        let addresses = if net.addresses.is_empty() {
+            Cell::from("-").style(Style::default().fg(Color::DarkGray))
+        } else {
+            Cell::from(net.addresses.join(", ")).style(Style::default().fg(Color::Gray))
+        };
+        let errors = net.rx_errors + net.tx_errors;
+        let error_style = if errors > 0 {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        Row::new(vec![
+            Cell::from(net.name.clone()),
+            addresses,
+            Cell::from(format_rate(net.rx_rate)).style(Style::default().fg(Color::Green)),
+            Cell::from(format_rate(net.tx_rate)).style(Style::default().fg(Color::Blue)),
+            Cell::from(format_bytes(net.total_received)),
+            Cell::from(format_bytes(net.total_transmitted)),
+            Cell::from(format!("{}/{}", net.rx_errors, net.tx_errors)).style(error_style),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Max(14),
+            Constraint::Min(16),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(i18n::fit("Received", 9)),
+            Constraint::Length(i18n::fit("Sent", 9)),
+            Constraint::Length(i18n::fit("Errors", 7)),
+        ],
+    )
+    .header(header)
+    .row_highlight_style(Style::default().bg(Color::Rgb(60, 60, 60)));
+
+    let mut state = TableState::default().with_selected(Some(selected.min(networks.len() - 1)));
+    frame.render_stateful_widget(table, area, &mut state);
+}
@@ -0,0 +1,152 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    components::{
+        cpu::usage_color,
+        text_input::{render_text_input, TextInput},
+    },
+    i18n::{self, t},
+    system::processes::{ProcessSort, ProcessTable},
+    units::format_bytes,
+};
+
+pub fn render_processes(frame: &mut Frame, area: Rect, table: &ProcessTable) {
+    let title = Line::from(vec![Span::raw(t("[== PROCESSES ==]"))])
+        .bold()
+        .centered();
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
+        .title(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(2)])
+        .split(inner);
+
+    render_toolbar(frame, chunks[0], table);
+    render_table(frame, chunks[1], table);
+}
+
+fn render_toolbar(frame: &mut Frame, area: Rect, table: &ProcessTable) {
+    let label = Style::default().fg(Color::DarkGray);
+    let order = if table.descending { "▼" } else { "▲" };
+    let status = Line::from(vec![
+        Span::styled(t("Sort: "), label),
+        Span::raw(format!("{} {order}", t(table.sort.title()))),
+        Span::styled(if table.tree { t("  Tree") } else { "" }, label),
+        Span::styled(
+            format!("  {}/{} ", table.rows.len(), table.processes.len()),
+            label,
+        ),
+    ]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(10),
+            Constraint::Length(status.width() as u16),
+        ])
+        .split(area);
+
+    let prefix = Span::styled(" / ", Style::default().fg(Color::Yellow));
+    let filter_area = Rect {
+        x: columns[0].x + prefix.width() as u16,
+        width: columns[0].width.saturating_sub(prefix.width() as u16),
+        ..columns[0]
+    };
+    frame.render_widget(Paragraph::new(Line::from(prefix)), columns[0]);
+    render_filter(frame, filter_area, &table.filter, table.filter_editing);
+    frame.render_widget(Paragraph::new(status).right_aligned(), columns[1]);
+}
+
+fn render_filter(frame: &mut Frame, area: Rect, filter: &TextInput, editing: bool) {
+    let placeholder = if editing {
+        t("type to filter, Enter to keep, Esc to clear")
+    } else {
+        t("filter")
+    };
+    render_text_input(
+        frame,
+        area,
+        filter,
+        Block::default(),
+        Span::styled(placeholder, Style::default().fg(Color::DarkGray)),
+        Style::default().fg(if editing { Color::White } else { Color::Gray }),
+        editing,
+    );
+}
+
+fn render_table(frame: &mut Frame, area: Rect, table: &ProcessTable) {
+    if table.rows.is_empty() {
+        let message = if table.processes.is_empty() {
+            t("No processes reported")
+        } else {
+            t("No processes match the filter")
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                message,
+                Style::default().fg(Color::DarkGray),
+            )))
+            .centered(),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(ProcessSort::ALL.map(|column| {
+        let style = Style::default().fg(Color::DarkGray).bold();
+        if column == table.sort {
+            let order = if table.descending { "▼" } else { "▲" };
+            Cell::from(format!("{}{order}", t(column.title()))).style(style.fg(Color::Yellow))
+        } else {
+            Cell::from(t(column.title())).style(style)
+        }
+    }));
+
+    let rows = table.rows.iter().map(|row| {
+        let process = &table.processes[row.index];
+        let name = if row.depth > 0 {
+            format!("{}└ {}", "  ".repeat(row.depth - 1), process.name)
+        } else {
+            process.name.clone()
+        };
+
+        Row::new(vec![
+            Cell::from(process.pid.to_string()).style(Style::default().fg(Color::Gray)),
+            Cell::from(name),
+            Cell::from(process.user.clone()).style(Style::default().fg(Color::Gray)),
+            Cell::from(format!("{:>5.1}", process.cpu))
+                .style(Style::default().fg(usage_color(process.cpu))),
+            Cell::from(format_bytes(process.memory)),
+            Cell::from(process.command.clone()).style(Style::default().fg(Color::DarkGray)),
+        ])
+    });
+
+    // One extra column for the sort arrow.
+    let widths = [
+        Constraint::Length(8),
+        Constraint::Length(if table.tree { 28 } else { 20 }),
+        Constraint::Length(i18n::fit(ProcessSort::User.title(), 9) + 1),
+        Constraint::Length(i18n::fit(ProcessSort::Cpu.title(), 5) + 1),
+        Constraint::Length(i18n::fit(ProcessSort::Memory.title(), 8) + 1),
+        Constraint::Min(10),
+    ];
+    let table_widget = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::Rgb(60, 60, 60))
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default().with_selected(Some(table.selected));
+    frame.render_stateful_widget(table_widget, area, &mut state);
+}
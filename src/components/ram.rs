@@ -6,16 +6,24 @@ use ratatui::{
     Frame,
 };
 
-use crate::types::SystemData;
-
-pub fn render_memory_info(frame: &mut Frame, area: Rect, text: &Text<'_>) {
-    let title = Line::from(vec![
-        Span::raw("Press `Ctrl-C` or `q` to stop running."),
-        Span::raw(" ?: Toggle help menu"),
-    ])
-    .bold()
-    .dark_gray()
-    .centered();
+use crate::{
+    components::cpu::usage_color,
+    i18n::t,
+    types::{Notice, SystemData},
+    units::{format_duration, format_gib, format_gib_of},
+};
+
+pub fn render_memory_info(frame: &mut Frame, area: Rect, text: &Text<'_>, notice: Option<&Notice>) {
+    let title = match notice {
+        Some(notice) => notice_line(notice).centered(),
+        None => Line::from(vec![
+            Span::raw(t("Press `Ctrl-C` or `q` to stop running.")),
+            Span::raw(t(" ?: Toggle help menu")),
+        ])
+        .bold()
+        .dark_gray()
+        .centered(),
+    };
     let block = Block::default().borders(Borders::TOP).title(title);
 
     frame.render_widget(
@@ -26,21 +34,112 @@ pub fn render_memory_info(frame: &mut Frame, area: Rect, text: &Text<'_>) {
     );
 }
 
+/// One-line system summary used when the system panel is collapsed.
+/// A pending notice takes the line over until it expires.
+pub fn render_memory_line(
+    frame: &mut Frame,
+    area: Rect,
+    data: &SystemData,
+    notice: Option<&Notice>,
+) {
+    let line = match notice {
+        Some(notice) => notice_line(notice),
+        None => format_sys_line(data),
+    };
+    frame.render_widget(Paragraph::new(line).alignment(Alignment::Left), area);
+}
+
+fn notice_line(notice: &Notice) -> Line<'static> {
+    let color = if notice.is_error {
+        Color::Red
+    } else {
+        Color::Green
+    };
+    Line::from(Span::styled(
+        format!(" {} ", notice.text),
+        Style::default().fg(color).bold(),
+    ))
+}
+
+pub fn format_sys_line(data: &SystemData) -> Line<'static> {
+    let label_color = Color::DarkGray;
+    let value_color = Color::White;
+    let used_color = if data.usage_memory > 80.0 {
+        Color::Red
+    } else {
+        value_color
+    };
+
+    let mut spans = vec![
+        Span::styled(t("CPU: "), Style::default().fg(label_color)),
+        Span::styled(
+            format!("{:.0}%", data.cpu.usage),
+            Style::default().fg(usage_color(data.cpu.usage)),
+        ),
+        Span::styled(
+            format!(" ({}x {} MHz)", data.cpu.len, data.cpu.frequency),
+            Style::default().fg(label_color),
+        ),
+        Span::raw("  "),
+        Span::styled(t("RAM: "), Style::default().fg(label_color)),
+        Span::styled(
+            format_gib_of(data.used_memory, data.total_memory, 1),
+            Style::default().fg(used_color),
+        ),
+        Span::styled(
+            format!(" ({:.0}%)", data.usage_memory),
+            Style::default().fg(label_color),
+        ),
+        Span::styled(t("  Load: "), Style::default().fg(label_color)),
+        Span::styled(
+            format!("{:.2}", data.host.load_one),
+            Style::default().fg(load_color(data.host.load_one, data.cpu.len)),
+        ),
+    ];
+    spans.extend(container_span(data));
+    spans.push(Span::styled(
+        t("  ?: help"),
+        Style::default().fg(label_color),
+    ));
+    Line::from(spans)
+}
+
+/// Indicator shown when running inside a container or limited cgroup.
+fn container_span(data: &SystemData) -> Option<Span<'static>> {
+    let container = data.container.as_ref()?;
+    Some(Span::styled(
+        format!("  ▣ {}", container.label()),
+        Style::default().fg(Color::Cyan),
+    ))
+}
+
+/// Load relative to the number of cores: red once every core is busy.
+pub fn load_color(load: f64, cores: usize) -> Color {
+    let cores = cores.max(1) as f64;
+    if load >= cores {
+        Color::Red
+    } else if load >= cores * 0.7 {
+        Color::Yellow
+    } else {
+        Color::White
+    }
+}
+
 pub fn format_sys_text(data: &SystemData) -> Text<'static> {
     let label_color = Color::DarkGray;
     let value_color = Color::White;
     let mut lines = Vec::new();
 
-    // CPU info line (первая строка)
+    // CPU info line
     let mut cpu_spans = Vec::new();
-    cpu_spans.push(Span::styled("CPU: ", Style::default().fg(label_color)));
+    cpu_spans.push(Span::styled(t("CPU: "), Style::default().fg(label_color)));
     cpu_spans.push(Span::styled(
         data.cpu.brand.clone(),
         Style::default().fg(value_color),
     ));
     cpu_spans.push(Span::raw("  "));
 
-    cpu_spans.push(Span::styled("Cores: ", Style::default().fg(label_color)));
+    cpu_spans.push(Span::styled(t("Cores: "), Style::default().fg(label_color)));
     cpu_spans.push(Span::styled(
         format!("{}", data.cpu.len),
         Style::default().fg(value_color),
@@ -48,48 +147,69 @@ pub fn format_sys_text(data: &SystemData) -> Text<'static> {
     cpu_spans.push(Span::raw("  "));
 
     cpu_spans.push(Span::styled(
-        "Frequency: ",
+        t("Frequency: "),
         Style::default().fg(label_color),
     ));
     cpu_spans.push(Span::styled(
         format!("{} MHz", data.cpu.frequency),
         Style::default().fg(value_color),
     ));
+    cpu_spans.push(Span::raw("  "));
+
+    cpu_spans.push(Span::styled(t("Usage: "), Style::default().fg(label_color)));
+    cpu_spans.push(Span::styled(
+        format!("{:.1}%", data.cpu.usage),
+        Style::default().fg(usage_color(data.cpu.usage)),
+    ));
+    if let Some(usage) = data.container.as_ref().and_then(|c| c.cpu_usage) {
+        cpu_spans.push(Span::styled(
+            t(" of host, "),
+            Style::default().fg(label_color),
+        ));
+        cpu_spans.push(Span::styled(
+            format!("{usage:.1}%"),
+            Style::default().fg(usage_color(usage)),
+        ));
+        cpu_spans.push(Span::styled(
+            t(" of quota"),
+            Style::default().fg(label_color),
+        ));
+    }
 
     lines.push(Line::from(cpu_spans));
 
-    // RAM info line (вторая строка)
+    // RAM info line
     let mut ram_spans = Vec::with_capacity(12);
-    ram_spans.push(Span::styled("Total: ", Style::default().fg(label_color)));
+    ram_spans.push(Span::styled(t("Total: "), Style::default().fg(label_color)));
     ram_spans.push(Span::styled(
-        format!("{:.2} GB", data.total_memory),
+        format_gib(data.total_memory, 2),
         Style::default().fg(value_color),
     ));
     ram_spans.push(Span::raw("  "));
 
-    ram_spans.push(Span::styled("Used: ", Style::default().fg(label_color)));
+    ram_spans.push(Span::styled(t("Used: "), Style::default().fg(label_color)));
     let used_color = if data.usage_memory > 80.0 {
         Color::Red
     } else {
         value_color
     };
     ram_spans.push(Span::styled(
-        format!("{:.2} GB", data.used_memory),
+        format_gib(data.used_memory, 2),
         Style::default().fg(used_color),
     ));
     ram_spans.push(Span::raw("  "));
 
     ram_spans.push(Span::styled(
-        "Available: ",
+        t("Available: "),
         Style::default().fg(label_color),
     ));
     ram_spans.push(Span::styled(
-        format!("{:.2} GB", data.available_memory),
+        format_gib(data.available_memory, 2),
         Style::default().fg(value_color),
     ));
     ram_spans.push(Span::raw("  "));
 
-    ram_spans.push(Span::styled("Usage: ", Style::default().fg(label_color)));
+    ram_spans.push(Span::styled(t("Usage: "), Style::default().fg(label_color)));
     ram_spans.push(Span::styled(
         format!("{:.1}%", data.usage_memory),
         Style::default().fg(value_color),
@@ -97,5 +217,43 @@ pub fn format_sys_text(data: &SystemData) -> Text<'static> {
 
     lines.push(Line::from(ram_spans));
 
+    // Swap, load and uptime line
+    let host = &data.host;
+    let swap = if data.swap_total > 0.0 {
+        format_gib_of(data.swap_used, data.swap_total, 2)
+    } else {
+        t("none").to_string()
+    };
+    let load_style = |load: f64| Style::default().fg(load_color(load, data.cpu.len));
+    let mut host_spans = vec![
+        Span::styled(t("Swap: "), Style::default().fg(label_color)),
+        Span::styled(swap, Style::default().fg(value_color)),
+        Span::raw("  "),
+        Span::styled(t("Load: "), Style::default().fg(label_color)),
+        Span::styled(format!("{:.2}", host.load_one), load_style(host.load_one)),
+        Span::raw(" "),
+        Span::styled(format!("{:.2}", host.load_five), load_style(host.load_five)),
+        Span::raw(" "),
+        Span::styled(
+            format!("{:.2}", host.load_fifteen),
+            load_style(host.load_fifteen),
+        ),
+        Span::raw("  "),
+        Span::styled(t("Uptime: "), Style::default().fg(label_color)),
+        Span::styled(
+            format_duration(host.uptime),
+            Style::default().fg(value_color),
+        ),
+        Span::raw("  "),
+        Span::styled(t("Host: "), Style::default().fg(label_color)),
+        Span::styled(host.hostname.clone(), Style::default().fg(value_color)),
+    ];
+    host_spans.extend(container_span(data));
+    host_spans.push(Span::styled(
+        t("  i: details"),
+        Style::default().fg(label_color),
+    ));
+    lines.push(Line::from(host_spans));
+
     Text::from(lines)
 }
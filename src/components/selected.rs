@@ -6,26 +6,33 @@ use ratatui::{
     Frame,
 };
 
-use crate::types::{ListState, TODOData};
+use crate::{
+    components::markdown::render_markdown,
+    i18n::{t, tf},
+    types::{ListState, Status, TODOData},
+    units::{format_date, format_datetime},
+};
 
-pub fn render_select(frame: &mut Frame, area: Rect, item: &ListState) {
+/// Render the selected task scrolled down by `scroll` lines. Returns how far
+/// it can be scrolled at most, so longer descriptions stay readable.
+pub fn render_select(frame: &mut Frame, area: Rect, item: &ListState, scroll: u16) -> u16 {
     if item.items.is_empty() {
-        return;
+        return 0;
     }
 
     let selected_index = match item.selected {
         Some(index) if index < item.items.len() => index,
         _ => {
-            return;
+            return 0;
         }
     };
 
     let data: &TODOData = &item.items[selected_index];
 
     let title = Line::from(vec![
-        Span::raw(format!("Title: {}", data.title)),
+        Span::raw(tf("Title: {}", &[&data.title])),
         Span::styled(
-            format!(" ({})", data.date.format("%d.%m.%y %H:%M")),
+            format!(" ({})", format_datetime(&data.date)),
             Style::default()
                 .fg(Color::White)
                 .add_modifier(Modifier::DIM),
@@ -36,20 +43,128 @@ pub fn render_select(frame: &mut Frame, area: Rect, item: &ListState) {
     .centered();
 
     let mut text = Vec::new();
-    text.push(Line::from(vec![
-        Span::raw("Status: "),
+    let mut meta = vec![
+        Span::raw(t("Status: ")),
         Span::styled(
-            format!("{:?}", data.status),
+            t(data.status.as_str()),
             Style::default()
                 .add_modifier(Modifier::BOLD)
                 .fg(data.status.get_color()),
         ),
-    ]));
-    text.push(Line::from(format!("Description: {}", data.message)));
+        Span::raw(t("  Priority: ")),
+        Span::styled(
+            t(data.priority.as_str()),
+            Style::default().fg(data.priority.get_color()),
+        ),
+    ];
+    if let Some(due) = data.due {
+        let overdue = due < chrono::Local::now().date_naive()
+            && !matches!(data.status, Status::Done | Status::Cancelled);
+        meta.push(Span::raw(t("  Due: ")));
+        meta.push(Span::styled(
+            format_date(due),
+            Style::default().fg(if overdue { Color::Red } else { Color::White }),
+        ));
+    }
+    text.push(Line::from(meta));
+    if !data.tags.is_empty() {
+        text.push(Line::from(vec![
+            Span::raw(t("Tags: ")),
+            Span::styled(
+                data.tags
+                    .iter()
+                    .map(|tag| format!("#{tag}"))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                Style::default().fg(Color::Rgb(140, 140, 180)),
+            ),
+        ]));
+    }
+    if !data.message.is_empty() {
+        text.push(Line::from(""));
+        text.extend(render_markdown(&data.message, usize::from(area.width)));
+    }
 
-    let block = Block::default()
+    let mut block = Block::default()
         .border_style(Style::default().fg(Color::White))
         .title(title);
 
-    frame.render_widget(Paragraph::new(text).block(block), area);
+    // One row goes to the title, one more to the indicator once it is needed.
+    let lines = u16::try_from(text.len()).unwrap_or(u16::MAX);
+    let max_scroll = if lines > area.height.saturating_sub(1) {
+        lines.saturating_sub(area.height.saturating_sub(2))
+    } else {
+        0
+    };
+    let scroll = scroll.min(max_scroll);
+    if max_scroll > 0 {
+        let hint = if scroll < max_scroll {
+            t("↓ more · PgUp/PgDn")
+        } else {
+            t("↑ PgUp")
+        };
+        block = block.title_bottom(
+            Line::from(Span::styled(
+                hint,
+                Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+            ))
+            .right_aligned(),
+        );
+    }
+
+    frame.render_widget(Paragraph::new(text).block(block).scroll((scroll, 0)), area);
+    max_scroll
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+    use crate::types::Priority;
+
+    fn draw(state: &ListState, scroll: u16) -> (u16, String) {
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        let mut max_scroll = 0;
+        terminal
+            .draw(|frame| max_scroll = render_select(frame, frame.area(), state, scroll))
+            .unwrap();
+        let text = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        (max_scroll, text)
+    }
+
+    #[test]
+    fn test_long_description_scrolls() {
+        let mut state = ListState::new();
+        state.items.push(TODOData {
+            id: 1,
+            title: "Notes".to_string(),
+            message: (1..=20)
+                .map(|n| format!("line {n}"))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            date: Local::now(),
+            status: Status::Todo,
+            priority: Priority::Medium,
+            due: None,
+            tags: Vec::new(),
+        });
+        state.selected = Some(0);
+
+        let (max_scroll, top) = draw(&state, 0);
+        assert!(max_scroll > 0);
+        assert!(top.contains("more"));
+        assert!(!top.contains("line 20"));
+
+        let (_, bottom) = draw(&state, u16::MAX);
+        assert!(bottom.contains("line 20"));
+        assert!(!bottom.contains("more"));
+    }
 }
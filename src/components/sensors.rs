@@ -0,0 +1,136 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::{
+    i18n::{self, t},
+    types::SensorInfo,
+    units::format_temperature,
+};
+
+const GAUGE_WIDTH: usize = 20;
+
+pub fn render_sensors(frame: &mut Frame, area: Rect, sensors: &[SensorInfo]) {
+    let title = Line::from(vec![Span::raw(t("[== SENSORS ==]"))])
+        .bold()
+        .centered();
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
+        .title(title);
+
+    if sensors.is_empty() {
+        let lines = vec![
+            Line::default(),
+            Line::from(Span::styled(
+                t("No sensors available"),
+                Style::default().fg(Color::Gray).bold(),
+            )),
+            Line::from(Span::styled(
+                t("This machine does not expose temperature sensors (common in VMs and containers)."),
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines)
+                .centered()
+                .wrap(ratatui::widgets::Wrap { trim: true })
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(
+        ["Sensor", "Current", "Max", "Critical", ""]
+            .map(|title| Cell::from(t(title)).style(Style::default().fg(Color::DarkGray).bold())),
+    );
+
+    let rows = sensors.iter().map(|sensor| {
+        let ratio = sensor.critical_ratio();
+        let color = ratio.map_or(Color::DarkGray, heat_color);
+        let style = if ratio.is_some_and(|ratio| ratio >= 1.0) {
+            Style::default().fg(color).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(color)
+        };
+
+        Row::new(vec![
+            Cell::from(sensor.label.clone()),
+            Cell::from(format_temp(sensor.temperature)).style(style),
+            Cell::from(format_temp(sensor.max)).style(Style::default().fg(Color::Gray)),
+            Cell::from(format_temp(sensor.critical)).style(Style::default().fg(Color::Gray)),
+            Cell::from(gauge(ratio.unwrap_or_default())).style(Style::default().fg(color)),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(16),
+            Constraint::Length(i18n::fit("Current", 9)),
+            Constraint::Length(i18n::fit("Max", 9)),
+            Constraint::Length(i18n::fit("Critical", 9)),
+            Constraint::Length(GAUGE_WIDTH as u16),
+        ],
+    )
+    .header(header)
+    .block(block);
+
+    frame.render_widget(table, area);
+}
+
+fn format_temp(temp: Option<f32>) -> String {
+    temp.map_or_else(
+        || "-".to_string(),
+        |temp| format_temperature(temp.into(), 1),
+    )
+}
+
+/// Color of a reading by its fraction of the critical temperature.
+fn heat_color(ratio: f32) -> Color {
+    if ratio >= 0.9 {
+        Color::Red
+    } else if ratio >= 0.75 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+fn gauge(ratio: f32) -> String {
+    let filled = (ratio.clamp(0.0, 1.0) * GAUGE_WIDTH as f32).round() as usize;
+    format!("{}{}", "■".repeat(filled), "·".repeat(GAUGE_WIDTH - filled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heat_color_approaching_critical() {
+        let sensor = |temperature, critical| SensorInfo {
+            label: "cpu".to_string(),
+            temperature: Some(temperature),
+            max: None,
+            critical,
+        };
+        let color = |sensor: SensorInfo| sensor.critical_ratio().map(heat_color);
+
+        assert_eq!(color(sensor(45.0, Some(100.0))), Some(Color::Green));
+        assert_eq!(color(sensor(80.0, Some(100.0))), Some(Color::Yellow));
+        assert_eq!(color(sensor(75.0, Some(80.0))), Some(Color::Red));
+        // Without a critical value the default threshold applies.
+        assert_eq!(color(sensor(95.0, None)), Some(Color::Red));
+    }
+
+    #[test]
+    fn test_format_temp() {
+        assert_eq!(format_temp(Some(41.25)), "41.2°C");
+        assert_eq!(format_temp(None), "-");
+    }
+}
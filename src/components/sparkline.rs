@@ -0,0 +1,50 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Paragraph, RenderDirection, Sparkline},
+    Frame,
+};
+
+use crate::system::history::RingBuffer;
+
+/// A sparkline of recent samples with a label column on its left.
+pub struct SeriesChart<'a> {
+    pub label: Vec<Line<'a>>,
+    pub label_width: u16,
+    pub color: Color,
+    /// Bar value drawn at full height.
+    pub max: u64,
+}
+
+/// Draw `series` scaled to bar values by `to_bar`. The newest sample sits at
+/// the right edge so the chart scrolls left.
+pub fn render_series(
+    frame: &mut Frame,
+    area: Rect,
+    chart: SeriesChart,
+    series: &RingBuffer<f64>,
+    to_bar: impl Fn(f64) -> u64,
+) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(chart.label_width), Constraint::Min(1)])
+        .split(area);
+    frame.render_widget(Paragraph::new(chart.label), columns[0]);
+
+    let width = usize::from(columns[1].width);
+    let data: Vec<u64> = series
+        .last_n(width)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .map(to_bar)
+        .collect();
+
+    let sparkline = Sparkline::default()
+        .data(data)
+        .max(chart.max)
+        .direction(RenderDirection::RightToLeft)
+        .style(Style::default().fg(chart.color));
+    frame.render_widget(sparkline, columns[1]);
+}
@@ -0,0 +1,26 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::Tabs,
+    Frame,
+};
+
+use crate::{app::View, i18n::t};
+
+pub fn render_tabs(frame: &mut Frame, area: Rect, current: View) {
+    let titles = View::ALL.iter().map(|view| Line::from(t(view.title())));
+    let selected = View::ALL.iter().position(|view| *view == current);
+
+    let tabs = Tabs::new(titles)
+        .select(selected)
+        .style(Style::default().fg(Color::DarkGray))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Rgb(255, 203, 164))
+                .add_modifier(Modifier::BOLD),
+        )
+        .divider("│");
+
+    frame.render_widget(tabs, area);
+}
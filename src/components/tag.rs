@@ -0,0 +1,90 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{
+    components::{
+        center_rect_min,
+        text_input::{render_text_input, TextInput},
+    },
+    i18n::{self, t, tf},
+};
+
+pub fn render_tag_modal(frame: &mut Frame, input: &TextInput, count: usize) {
+    let title = if count == 1 {
+        t(" TAG 1 TASK ").to_string()
+    } else {
+        tf(" TAG {} TASKS ", &[&count])
+    };
+    let min_width = 36.max(i18n::width(&title) + 4);
+    let modal_area = center_rect_min(30, 20, min_width, 8, frame.area());
+    frame.render_widget(Clear, modal_area);
+
+    let modal_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .border_type(BorderType::Double)
+        .style(Style::default().bg(Color::Rgb(25, 25, 35)))
+        .title(
+            Line::from(Span::styled(
+                title,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+        );
+    frame.render_widget(modal_block, modal_area);
+
+    let content_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .horizontal_margin(2)
+        .vertical_margin(1)
+        .constraints([
+            Constraint::Length(3), // Tag input
+            Constraint::Length(1), // Instructions
+        ])
+        .split(modal_area);
+
+    let input_style = Style::default().fg(Color::White).bg(Color::Rgb(30, 30, 40));
+    render_text_input(
+        frame,
+        content_layout[0],
+        input,
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(
+                Style::default()
+                    .fg(Color::LightBlue)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .style(input_style),
+        Span::styled(t("tag name"), Style::default().fg(Color::DarkGray)),
+        input_style,
+        true,
+    );
+
+    let instructions = Paragraph::new(
+        Line::from(vec![
+            Span::styled(
+                " ENTER ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(t("Apply "), Style::default().fg(Color::Gray)),
+            Span::styled(
+                " ESC ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(t("Cancel"), Style::default().fg(Color::Gray)),
+        ])
+        .alignment(Alignment::Center),
+    )
+    .style(Style::default().bg(Color::Rgb(25, 25, 35)));
+    frame.render_widget(instructions, content_layout[1]);
+}
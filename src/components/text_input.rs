@@ -0,0 +1,463 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    Frame,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Text input with a grapheme-aware cursor, single-line by default.
+///
+/// The cursor is stored as a byte offset that always sits on a grapheme
+/// boundary, and the optional length limit is counted in graphemes so that
+/// non-ASCII text gets the same budget as ASCII. A multi-line input accepts
+/// Enter as a newline and moves between lines with Up/Down.
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    value: String,
+    cursor: usize,
+    max_len: Option<usize>,
+    multiline: bool,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self {
+            max_len: Some(max_len),
+            ..Self::default()
+        }
+    }
+
+    pub fn multiline() -> Self {
+        Self {
+            multiline: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Number of user-perceived characters in the value.
+    pub fn len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// Byte offset of the cursor inside [`TextInput::value`].
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    pub fn set_value(&mut self, value: &str) {
+        self.clear();
+        self.insert_str(value);
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let mut buf = [0; 4];
+        self.insert_str(c.encode_utf8(&mut buf));
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        let allowed = match self.max_len {
+            Some(max) => max.saturating_sub(self.len()),
+            None => usize::MAX,
+        };
+        let mut text: String = text.graphemes(true).take(allowed).collect();
+        if !self.multiline {
+            text = text.replace(['\r', '\n'], " ");
+        }
+        if text.is_empty() {
+            return;
+        }
+
+        self.value.insert_str(self.cursor, &text);
+        self.cursor += text.len();
+        // A combining mark may have merged with the previous grapheme.
+        self.cursor = self.snap_to_boundary(self.cursor);
+    }
+
+    pub fn backspace(&mut self) {
+        let start = self.prev_boundary(self.cursor);
+        self.value.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    pub fn delete(&mut self) {
+        let end = self.next_boundary(self.cursor);
+        self.value.replace_range(self.cursor..end, "");
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.prev_boundary(self.cursor);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = self.next_boundary(self.cursor);
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = self.line_start(self.cursor);
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.line_end(self.cursor);
+    }
+
+    pub fn move_up(&mut self) {
+        let start = self.line_start(self.cursor);
+        if start > 0 {
+            let column = self.cursor_column();
+            self.cursor = self.offset_at_column(self.line_start(start - 1), column);
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        let end = self.line_end(self.cursor);
+        if end < self.value.len() {
+            let column = self.cursor_column();
+            self.cursor = self.offset_at_column(end + 1, column);
+        }
+    }
+
+    pub fn move_word_left(&mut self) {
+        self.cursor = self.word_start_before(self.cursor);
+    }
+
+    pub fn move_word_right(&mut self) {
+        self.cursor = self.word_end_after(self.cursor);
+    }
+
+    /// Ctrl-W: delete the word before the cursor.
+    pub fn delete_word_before(&mut self) {
+        let start = self.word_start_before(self.cursor);
+        self.value.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Ctrl-U: delete everything before the cursor on the current line.
+    pub fn delete_to_start(&mut self) {
+        let start = self.line_start(self.cursor);
+        self.value.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Apply an editing key. Returns `false` when the key is not an editing key,
+    /// so callers can handle it themselves (Enter, Tab, Esc, ...).
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('w' | 'W'))
+            | (KeyModifiers::ALT, KeyCode::Backspace) => self.delete_word_before(),
+            (KeyModifiers::CONTROL, KeyCode::Char('u' | 'U')) => self.delete_to_start(),
+            (KeyModifiers::ALT, KeyCode::Char('b' | 'B'))
+            | (KeyModifiers::CONTROL, KeyCode::Left) => self.move_word_left(),
+            (KeyModifiers::ALT, KeyCode::Char('f' | 'F'))
+            | (KeyModifiers::CONTROL, KeyCode::Right) => self.move_word_right(),
+            (_, KeyCode::Left) => self.move_left(),
+            (_, KeyCode::Right) => self.move_right(),
+            (_, KeyCode::Up) if self.multiline => self.move_up(),
+            (_, KeyCode::Down) if self.multiline => self.move_down(),
+            (_, KeyCode::Enter) if self.multiline => self.insert_char('\n'),
+            (_, KeyCode::Home) => self.move_home(),
+            (_, KeyCode::End) => self.move_end(),
+            (_, KeyCode::Backspace) => self.backspace(),
+            (_, KeyCode::Delete) => self.delete(),
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => self.insert_char(c),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Display width (terminal columns) of the text before the cursor on its line.
+    pub fn cursor_column(&self) -> usize {
+        self.value[self.line_start(self.cursor)..self.cursor].width()
+    }
+
+    /// Zero-based line the cursor is on.
+    pub fn cursor_row(&self) -> usize {
+        self.value[..self.cursor].matches('\n').count()
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        self.value[..offset].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self, offset: usize) -> usize {
+        self.value[offset..]
+            .find('\n')
+            .map_or(self.value.len(), |i| offset + i)
+    }
+
+    /// Offset on the line starting at `line_start` closest to display `column`.
+    fn offset_at_column(&self, line_start: usize, column: usize) -> usize {
+        let line = &self.value[line_start..self.line_end(line_start)];
+        let mut width = 0;
+        for (i, grapheme) in line.grapheme_indices(true) {
+            width += grapheme.width();
+            if width > column {
+                return line_start + i;
+            }
+        }
+        line_start + line.len()
+    }
+
+    fn prev_boundary(&self, offset: usize) -> usize {
+        self.value[..offset]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(i, _)| i)
+    }
+
+    fn next_boundary(&self, offset: usize) -> usize {
+        self.value[offset..]
+            .graphemes(true)
+            .next()
+            .map_or(offset, |g| offset + g.len())
+    }
+
+    fn snap_to_boundary(&self, offset: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .map(|(i, g)| i + g.len())
+            .find(|end| *end >= offset)
+            .unwrap_or(0)
+    }
+
+    fn word_start_before(&self, offset: usize) -> usize {
+        let mut graphemes = self.value[..offset].grapheme_indices(true).rev().peekable();
+        let mut start = offset;
+
+        while let Some((i, _)) = graphemes.next_if(|(_, g)| !is_word(g)) {
+            start = i;
+        }
+        while let Some((i, _)) = graphemes.next_if(|(_, g)| is_word(g)) {
+            start = i;
+        }
+        start
+    }
+
+    fn word_end_after(&self, offset: usize) -> usize {
+        let mut graphemes = self.value[offset..].grapheme_indices(true).peekable();
+        let mut end = offset;
+
+        while let Some((i, g)) = graphemes.next_if(|(_, g)| !is_word(g)) {
+            end = offset + i + g.len();
+        }
+        while let Some((i, g)) = graphemes.next_if(|(_, g)| is_word(g)) {
+            end = offset + i + g.len();
+        }
+        end
+    }
+}
+
+fn is_word(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Render `input` inside `block`, scrolling so the cursor stays visible.
+/// When `focused` is set the terminal cursor is placed at the caret.
+pub fn render_text_input(
+    frame: &mut Frame,
+    area: Rect,
+    input: &TextInput,
+    block: Block<'_>,
+    placeholder: Span<'_>,
+    style: Style,
+    focused: bool,
+) {
+    let inner = block.inner(area);
+    let width = usize::from(inner.width);
+    let height = usize::from(inner.height);
+
+    let cursor_column = input.cursor_column();
+    let cursor_row = input.cursor_row();
+    let scroll_x = (cursor_column + 1).saturating_sub(width);
+    let scroll_y = (cursor_row + 1).saturating_sub(height);
+
+    let lines: Vec<Line> = if input.is_empty() {
+        vec![Line::from(placeholder)]
+    } else {
+        input
+            .value()
+            .split('\n')
+            .skip(scroll_y)
+            .take(height)
+            .map(|line| Line::from(Span::styled(visible_slice(line, scroll_x, width), style)))
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines).style(style).block(block), area);
+
+    if focused && inner.width > 0 && inner.height > 0 {
+        let x = u16::try_from(cursor_column - scroll_x).unwrap_or(u16::MAX);
+        let y = u16::try_from(cursor_row - scroll_y).unwrap_or(u16::MAX);
+        frame.set_cursor_position(Position::new(
+            inner.x.saturating_add(x).min(inner.right() - 1),
+            inner.y.saturating_add(y).min(inner.bottom() - 1),
+        ));
+    }
+}
+
+/// Cut `text` to the graphemes covering columns `scroll..scroll + width`.
+/// A wide grapheme straddling the left edge is replaced by padding so the
+/// remaining text keeps its column positions.
+fn visible_slice(text: &str, scroll: usize, width: usize) -> String {
+    let mut column = 0;
+    let mut visible = String::new();
+
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = grapheme.width();
+        if column >= scroll {
+            if column + grapheme_width > scroll + width {
+                break;
+            }
+            visible.push_str(grapheme);
+        } else if column + grapheme_width > scroll {
+            visible.push_str(&" ".repeat(column + grapheme_width - scroll));
+        }
+        column += grapheme_width;
+    }
+    visible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_with(text: &str) -> TextInput {
+        let mut input = TextInput::new();
+        input.set_value(text);
+        input
+    }
+
+    #[test]
+    fn test_limit_counts_graphemes_not_bytes() {
+        let mut input = TextInput::with_max_len(5);
+        for c in "привет".chars() {
+            input.insert_char(c);
+        }
+        assert_eq!(input.value(), "приве");
+        assert_eq!(input.len(), 5);
+    }
+
+    #[test]
+    fn test_insert_in_the_middle() {
+        let mut input = input_with("hllo");
+        input.move_home();
+        input.move_right();
+        input.insert_char('e');
+        assert_eq!(input.value(), "hello");
+        assert_eq!(input.cursor(), 2);
+    }
+
+    #[test]
+    fn test_cursor_moves_over_whole_graphemes() {
+        let mut input = input_with("a👍🏽b");
+        input.move_left();
+        input.move_left();
+        assert_eq!(input.cursor(), 1);
+        input.delete();
+        assert_eq!(input.value(), "ab");
+    }
+
+    #[test]
+    fn test_backspace_removes_combined_grapheme() {
+        let mut input = input_with("cafe\u{301}");
+        input.backspace();
+        assert_eq!(input.value(), "caf");
+    }
+
+    #[test]
+    fn test_word_motions_and_deletes() {
+        let mut input = input_with("buy fresh milk");
+        input.move_word_left();
+        assert_eq!(input.cursor(), 10);
+        input.move_word_left();
+        assert_eq!(input.cursor(), 4);
+        input.move_word_right();
+        assert_eq!(input.cursor(), 9);
+
+        input.move_end();
+        input.delete_word_before();
+        assert_eq!(input.value(), "buy fresh ");
+        input.delete_to_start();
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_column_uses_display_width() {
+        let input = input_with("日本語");
+        assert_eq!(input.cursor_column(), 6);
+    }
+
+    #[test]
+    fn test_single_line_input_flattens_newlines() {
+        let input = input_with("one\ntwo");
+        assert_eq!(input.value(), "one two");
+    }
+
+    #[test]
+    fn test_multiline_vertical_motion_keeps_column() {
+        let mut input = TextInput::multiline();
+        input.set_value("first line\nab\nthird");
+        assert_eq!(input.cursor_row(), 2);
+
+        input.move_up();
+        assert_eq!(input.cursor_row(), 1);
+        assert_eq!(input.cursor_column(), 2);
+
+        input.move_up();
+        assert_eq!(input.cursor_column(), 2);
+        input.move_end();
+        input.move_down();
+        assert_eq!(input.cursor_column(), 2);
+        input.move_down();
+        assert_eq!(input.cursor_column(), 2);
+        input.move_home();
+        input.insert_str("- ");
+        assert_eq!(input.value(), "first line\nab\n- third");
+    }
+
+    #[test]
+    fn test_multiline_enter_inserts_newline() {
+        let mut input = TextInput::multiline();
+        input.set_value("ab");
+        input.move_left();
+        input.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(input.value(), "a\nb");
+
+        input.delete_to_start();
+        assert_eq!(input.value(), "a\nb");
+    }
+
+    #[test]
+    fn test_visible_slice_scrolls_wide_text() {
+        assert_eq!(visible_slice("日本語abc", 2, 4), "本語");
+        assert_eq!(visible_slice("日本語abc", 3, 4), " 語a");
+        assert_eq!(visible_slice("hello", 0, 10), "hello");
+    }
+}
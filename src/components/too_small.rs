@@ -0,0 +1,51 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    components::{MIN_HEIGHT, MIN_WIDTH},
+    i18n::t,
+};
+
+pub fn render_too_small(frame: &mut Frame) {
+    let area = frame.area();
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Length(3),
+            Constraint::Fill(1),
+        ])
+        .split(area);
+
+    let message = Paragraph::new(vec![
+        Line::from(Span::styled(
+            t("Terminal too small"),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled(t("Current: "), Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{}x{}", area.width, area.height),
+                Style::default().fg(Color::Red),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(t("Required: "), Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{MIN_WIDTH}x{MIN_HEIGHT}"),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+    ])
+    .alignment(Alignment::Center);
+
+    frame.render_widget(message, rows[1]);
+}
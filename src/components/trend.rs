@@ -0,0 +1,180 @@
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use crate::{
+    i18n::{t, tf},
+    system::metrics::{Metric, MetricUnit, TimeRange, TrendState},
+    units::{self, format_rate},
+};
+
+pub fn render_trend(frame: &mut Frame, area: Rect, trend: &TrendState) {
+    let title = Line::from(vec![Span::raw(t("[== HISTORY ==]"))])
+        .bold()
+        .centered();
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
+        .title(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(3),
+        ])
+        .split(inner);
+
+    render_selectors(frame, chunks[0], trend);
+    render_stats(frame, chunks[1], trend);
+    render_chart(frame, chunks[2], trend);
+}
+
+fn render_selectors(frame: &mut Frame, area: Rect, trend: &TrendState) {
+    let selected = Style::default()
+        .fg(Color::Rgb(255, 203, 164))
+        .add_modifier(Modifier::BOLD);
+    let other = Style::default().fg(Color::DarkGray);
+
+    let mut spans = vec![Span::raw(" ")];
+    for metric in Metric::ALL {
+        let style = if metric == trend.metric {
+            selected
+        } else {
+            other
+        };
+        spans.push(Span::styled(t(metric.title()), style));
+        spans.push(Span::styled(" │ ", other));
+    }
+    spans.pop();
+    spans.push(Span::raw("    "));
+    for range in TimeRange::ALL {
+        let style = if range == trend.range {
+            selected
+        } else {
+            other
+        };
+        spans.push(Span::styled(format!(" {} ", t(range.label())), style));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_stats(frame: &mut Frame, area: Rect, trend: &TrendState) {
+    let label = Style::default().fg(Color::DarkGray);
+    let unit = trend.metric.unit();
+    let line = match stats(&trend.points) {
+        Some((min, avg, max)) => Line::from(vec![
+            Span::styled(t(" min "), label),
+            Span::raw(format_value(min, unit)),
+            Span::styled(t("  avg "), label),
+            Span::raw(format_value(avg, unit)),
+            Span::styled(t("  max "), label),
+            Span::raw(format_value(max, unit)),
+            Span::styled(tf("  ({} samples)", &[&trend.points.len()]), label),
+        ]),
+        None => Line::from(Span::styled(
+            t(" No samples recorded for this range yet"),
+            label,
+        )),
+    };
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn render_chart(frame: &mut Frame, area: Rect, trend: &TrendState) {
+    let now = Local::now().timestamp() as f64;
+    let from = now - trend.range.seconds() as f64;
+    let unit = trend.metric.unit();
+
+    let max = match unit {
+        MetricUnit::Percent => 100.0,
+        _ => {
+            trend
+                .points
+                .iter()
+                .map(|(_, value)| *value)
+                .fold(0.0, f64::max)
+                .max(1.0)
+                * 1.1
+        }
+    };
+
+    let dataset = Dataset::default()
+        .marker(Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Cyan))
+        .data(&trend.points);
+
+    let format: fn(&DateTime<Local>) -> String =
+        if trend.range.seconds() <= TimeRange::Day.seconds() {
+            units::format_time
+        } else {
+            units::format_datetime
+        };
+    let x_labels = [from, (from + now) / 2.0, now]
+        .map(|ts| Span::styled(format_timestamp(ts, format), Color::DarkGray));
+    let y_labels =
+        [0.0, max / 2.0, max].map(|value| Span::styled(format_value(value, unit), Color::DarkGray));
+
+    let chart = Chart::new(vec![dataset])
+        .x_axis(
+            Axis::default()
+                .bounds([from, now])
+                .labels(x_labels)
+                .style(Style::default().fg(Color::Rgb(80, 80, 80))),
+        )
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, max])
+                .labels(y_labels)
+                .style(Style::default().fg(Color::Rgb(80, 80, 80))),
+        );
+    frame.render_widget(chart, area);
+}
+
+fn stats(points: &[(f64, f64)]) -> Option<(f64, f64, f64)> {
+    if points.is_empty() {
+        return None;
+    }
+    let (min, max, sum) = points.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY, 0.0),
+        |(min, max, sum), (_, value)| (min.min(*value), max.max(*value), sum + value),
+    );
+    Some((min, sum / points.len() as f64, max))
+}
+
+fn format_value(value: f64, unit: MetricUnit) -> String {
+    match unit {
+        MetricUnit::Percent => format!("{value:.0}%"),
+        MetricUnit::BytesPerSec => format_rate(value),
+        MetricUnit::Plain => format!("{value:.2}"),
+    }
+}
+
+fn format_timestamp(ts: f64, format: fn(&DateTime<Local>) -> String) -> String {
+    DateTime::from_timestamp(ts as i64, 0)
+        .map(|time| format(&time.with_timezone(&Local)))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(stats(&[]), None);
+        assert_eq!(
+            stats(&[(0.0, 10.0), (1.0, 30.0), (2.0, 20.0)]),
+            Some((10.0, 20.0, 30.0))
+        );
+    }
+}
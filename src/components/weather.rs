@@ -0,0 +1,293 @@
+use chrono::Local;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Cell, Chart, Dataset, GraphType, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::{
+    i18n::{self, t},
+    types::{Forecast, ForecastMode, HourlyForecast, WeatherInfo},
+    units::{self, format_speed, format_temperature},
+};
+
+/// Rows taken by the expanded weather panel, including its border.
+pub const WEATHER_PANEL_HEIGHT: u16 = 10;
+/// Hours covered by the temperature chart.
+const CHART_HOURS: usize = 24;
+
+pub fn render_weather(
+    frame: &mut Frame,
+    area: Rect,
+    weather: Option<&WeatherInfo>,
+    forecast: Option<&Forecast>,
+    mode: ForecastMode,
+) {
+    let title = Line::from(vec![Span::raw(t("[== WEATHER ==]"))])
+        .bold()
+        .centered();
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(Color::Rgb(80, 80, 80)))
+        .title(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(28),
+            Constraint::Length(34),
+            Constraint::Min(20),
+        ])
+        .split(inner);
+
+    render_current(frame, chunks[0], weather);
+    match forecast {
+        Some(forecast) => {
+            render_table(frame, chunks[1], forecast, mode);
+            render_chart(
+                frame,
+                chunks[2],
+                forecast.upcoming(Local::now(), CHART_HOURS),
+            );
+        }
+        None => frame.render_widget(
+            Paragraph::new(Span::styled(
+                t("Forecast not loaded yet"),
+                Style::default().fg(Color::DarkGray),
+            )),
+            chunks[1],
+        ),
+    }
+}
+
+fn render_current(frame: &mut Frame, area: Rect, weather: Option<&WeatherInfo>) {
+    let label = Style::default().fg(Color::DarkGray);
+    let value = Style::default().fg(Color::White);
+    let Some(weather) = weather else {
+        frame.render_widget(
+            Paragraph::new(Span::styled(t(" No weather data"), label)),
+            area,
+        );
+        return;
+    };
+
+    let names = ["Temp", "Feels like", "Humidity", "Wind", "Pressure"];
+    let width = usize::from(i18n::max_width(&names).max(10) + 1);
+    let row = |name: &'static str, text: String| {
+        Line::from(vec![
+            Span::styled(format!(" {:<width$}", t(name)), label),
+            Span::styled(text, value),
+        ])
+    };
+    let lines = vec![
+        Line::from(vec![
+            Span::raw(format!(" {} ", weather.condition.glyph())),
+            Span::styled(weather.description.clone(), value.bold()),
+        ]),
+        Line::from(Span::styled(format!(" {}", weather.name), label)),
+        row("Temp", format_temperature(weather.temp_c, 0)),
+        row("Feels like", format_temperature(weather.feels_like_c, 0)),
+        row("Humidity", format!("{:.0}%", weather.humidity)),
+        row(
+            "Wind",
+            format!(
+                "{} {}",
+                format_speed(weather.wind_speed),
+                compass(weather.wind_direction)
+            ),
+        ),
+        row("Pressure", format!("{:.0} hPa", weather.pressure)),
+    ];
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_table(frame: &mut Frame, area: Rect, forecast: &Forecast, mode: ForecastMode) {
+    let header_style = Style::default()
+        .fg(Color::DarkGray)
+        .add_modifier(Modifier::BOLD);
+    let chance = |value: Option<f64>| value.map_or_else(String::new, |v| format!("{v:.0}%"));
+
+    let (title, rows): (&str, Vec<Row>) = match mode {
+        ForecastMode::Hourly => (
+            t("Hour"),
+            forecast
+                .upcoming(Local::now(), area.height.into())
+                .iter()
+                .map(|point| {
+                    Row::new([
+                        Cell::from(units::format_time(&point.time)),
+                        Cell::from(point.condition.glyph()),
+                        Cell::from(format_temperature(point.temp_c, 0)),
+                        Cell::from(chance(point.precipitation_chance)).fg(Color::Cyan),
+                    ])
+                })
+                .collect(),
+        ),
+        ForecastMode::Daily => (
+            t("Day"),
+            forecast
+                .daily
+                .iter()
+                .map(|day| {
+                    Row::new([
+                        Cell::from(units::format_date(day.date)),
+                        Cell::from(day.condition.glyph()),
+                        Cell::from(format!(
+                            "{:.0}…{}",
+                            units::settings().temperature.convert(day.min_c),
+                            format_temperature(day.max_c, 0)
+                        )),
+                        Cell::from(chance(day.precipitation_chance)).fg(Color::Cyan),
+                    ])
+                })
+                .collect(),
+        ),
+    };
+
+    let header = Row::new(
+        [title, "", t("Temp"), t("Rain")].map(|title| Cell::from(title).style(header_style)),
+    );
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(2),
+            Constraint::Length(i18n::fit("Temp", 11)),
+            Constraint::Length(i18n::fit("Rain", 5)),
+        ],
+    )
+    .header(header);
+    frame.render_widget(table, area);
+}
+
+fn render_chart(frame: &mut Frame, area: Rect, points: &[HourlyForecast]) {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return;
+    };
+    let unit = units::settings().temperature;
+    let start = first.time.timestamp() as f64;
+    let end = (last.time.timestamp() as f64).max(start + 1.0);
+    let data: Vec<(f64, f64)> = points
+        .iter()
+        .map(|point| (point.time.timestamp() as f64, unit.convert(point.temp_c)))
+        .collect();
+    let (min, max) = data
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), (_, t)| {
+            (min.min(*t), max.max(*t))
+        });
+    let (min, max) = (min.floor() - 1.0, max.ceil() + 1.0);
+
+    let dataset = Dataset::default()
+        .marker(Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Rgb(255, 203, 164)))
+        .data(&data);
+    let mid = &points[points.len() / 2];
+    let x_labels = [first, mid, last]
+        .map(|point| Span::styled(units::format_time(&point.time), Color::DarkGray));
+    let y_labels = [min, max].map(|t| Span::styled(format!("{t:.0}°"), Color::DarkGray));
+
+    let chart = Chart::new(vec![dataset])
+        .x_axis(
+            Axis::default()
+                .bounds([start, end])
+                .labels(x_labels)
+                .style(Style::default().fg(Color::Rgb(80, 80, 80))),
+        )
+        .y_axis(
+            Axis::default()
+                .bounds([min, max])
+                .labels(y_labels)
+                .style(Style::default().fg(Color::Rgb(80, 80, 80))),
+        );
+    frame.render_widget(chart, area);
+}
+
+/// Eight-point compass name of the direction the wind blows from.
+fn compass(degrees: f64) -> &'static str {
+    const POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    let index = ((degrees.rem_euclid(360.0) + 22.5) / 45.0) as usize % POINTS.len();
+    POINTS[index]
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, FixedOffset, NaiveDate};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+    use crate::types::{DailyForecast, WeatherCondition};
+
+    #[test]
+    fn test_render_panel() {
+        let now = Local::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+        let forecast = Forecast {
+            hourly: (0..30)
+                .map(|h| HourlyForecast {
+                    time: now + Duration::hours(h),
+                    temp_c: 10.0 + h as f64 / 3.0,
+                    condition: WeatherCondition::Clear,
+                    precipitation_chance: Some(20.0),
+                })
+                .collect(),
+            daily: vec![DailyForecast {
+                date: NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+                min_c: 4.0,
+                max_c: 12.0,
+                condition: WeatherCondition::Rain,
+                precipitation_chance: Some(80.0),
+            }],
+        };
+        let weather = WeatherInfo {
+            name: "Berlin".to_string(),
+            temp_c: 11.6,
+            feels_like_c: 9.2,
+            humidity: 70.0,
+            wind_speed: 3.4,
+            wind_direction: 225.0,
+            pressure: 1015.0,
+            description: "clear sky".to_string(),
+            condition: WeatherCondition::Clear,
+        };
+
+        let mut terminal = Terminal::new(TestBackend::new(100, WEATHER_PANEL_HEIGHT)).unwrap();
+        terminal
+            .draw(|frame| {
+                render_weather(
+                    frame,
+                    frame.area(),
+                    Some(&weather),
+                    Some(&forecast),
+                    ForecastMode::Daily,
+                )
+            })
+            .unwrap();
+
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("WEATHER"));
+        assert!(text.contains("Feels like 9°C"));
+        assert!(text.contains("3.4 m/s SW"));
+        assert!(text.contains("18.10.26"));
+        assert!(text.contains("4…12°C"));
+    }
+
+    #[test]
+    fn test_compass() {
+        assert_eq!(compass(0.0), "N");
+        assert_eq!(compass(350.0), "N");
+        assert_eq!(compass(230.0), "SW");
+        assert_eq!(compass(-90.0), "W");
+    }
+}
@@ -6,7 +6,11 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::App;
+use crate::{
+    app::App,
+    i18n::{t, tf},
+    units::{format_clock, format_duration, format_temperature},
+};
 
 fn get_loading_animation(frame: usize) -> &'static str {
     match frame % 4 {
@@ -18,42 +22,91 @@ fn get_loading_animation(frame: usize) -> &'static str {
     }
 }
 
+/// Position among the saved locations, shown when there are several to
+/// cycle through with `L`.
+fn location_hint(app: &App) -> Span<'static> {
+    match app.location_position() {
+        Some((label, index, count)) => Span::styled(
+            format!(" [{label} {index}/{count} · L]"),
+            Style::default().fg(Color::DarkGray),
+        ),
+        None => Span::raw(""),
+    }
+}
+
+/// Age of stale weather and, after a failed fetch, a warning with the time
+/// until the next retry.
+fn freshness_spans(app: &App) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    if let Some(age) = app.weather_stale_age() {
+        let secs = u64::try_from(age.num_seconds()).unwrap_or(0);
+        spans.push(Span::styled(
+            tf(" · {} ago", &[&format_duration(secs)]),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if let Some(retry_in) = app.weather_retry_in() {
+        spans.push(Span::styled(" ⚠", Style::default().fg(Color::Red).bold()));
+        spans.push(Span::styled(
+            tf(" retry in {}s · r", &[&retry_in]),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    spans
+}
+
 pub fn render_welcome(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let datetime: DateTime<Local> = Local::now();
     let label_color = Color::DarkGray;
     let value_color = Color::White;
 
     let title_line = if let Some(weather) = app.weather_info() {
-        Line::from(vec![
+        let mut spans = vec![
             Span::styled("TUITASK ", Style::default().fg(value_color).bold()),
             Span::styled("[", Style::default().fg(label_color)),
-            Span::styled(
-                datetime.format("%H:%M:%S").to_string(),
-                Style::default().fg(value_color),
-            ),
+            Span::styled(format_clock(&datetime), Style::default().fg(value_color)),
             Span::styled("] [", Style::default().fg(label_color)),
             Span::styled(&weather.name, Style::default().fg(value_color)),
             Span::styled(" ", Style::default()),
             Span::styled(
-                format!("{:.0}°C", weather.temp_c),
+                format_temperature(weather.temp_c, 0),
                 Style::default().fg(value_color),
             ),
+        ];
+        spans.extend(freshness_spans(app));
+        spans.push(Span::styled("]", Style::default().fg(label_color)));
+        spans.push(location_hint(app));
+        Line::from(spans)
+    } else if app.privacy_mode() {
+        Line::from(vec![
+            Span::styled("TUITASK ", Style::default().fg(value_color).bold()),
+            Span::styled("[", Style::default().fg(label_color)),
+            Span::styled(format_clock(&datetime), Style::default().fg(value_color)),
+            Span::styled("] [", Style::default().fg(label_color)),
+            Span::styled(t("Privacy mode"), Style::default().fg(label_color)),
             Span::styled("]", Style::default().fg(label_color)),
         ])
     } else {
         let dots = get_loading_animation(app.animation_frame);
-        Line::from(vec![
+        let mut spans = vec![
             Span::styled("TUI DISPATCHER ", Style::default().fg(label_color).bold()),
             Span::styled("[", Style::default().fg(label_color)),
-            Span::styled(
-                datetime.format("%H:%M:%S").to_string(),
-                Style::default().fg(value_color),
-            ),
+            Span::styled(format_clock(&datetime), Style::default().fg(value_color)),
             Span::styled("] [", Style::default().fg(label_color)),
-            Span::styled("Loading ", Style::default().fg(value_color)),
-            Span::styled(dots, Style::default().fg(value_color)),
-            Span::styled("]", Style::default().fg(label_color)),
-        ])
+        ];
+        if app.weather_retry_in().is_some() {
+            spans.push(Span::styled(t("Offline"), Style::default().fg(value_color)));
+            spans.extend(freshness_spans(app));
+        } else {
+            spans.push(Span::styled(
+                t("Loading "),
+                Style::default().fg(value_color),
+            ));
+            spans.push(Span::styled(dots, Style::default().fg(value_color)));
+        }
+        spans.push(Span::styled("]", Style::default().fg(label_color)));
+        spans.push(location_hint(app));
+        Line::from(spans)
     };
 
     let block = Block::default()
@@ -0,0 +1,310 @@
+use std::{env, net::SocketAddr, path::PathBuf, time::Duration};
+
+use crate::{
+    http::HttpSettings,
+    i18n::Language,
+    system::{
+        alerts::{AlertMetric, AlertRule},
+        sampler::RefreshRates,
+    },
+    units::{self, ByteUnit, Locale, SpeedUnit, TemperatureUnit, UnitSettings},
+    weather::{Location, ProviderKind},
+};
+
+/// Runtime settings, read from environment variables (a `.env` file is
+/// loaded into the environment by `main` before this runs).
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// How much CPU/memory history is kept for the sparklines
+    /// (`TUITASK_HISTORY_SECS`, default 5 minutes).
+    pub history_window: Duration,
+    /// Resource alert rules (`TUITASK_ALERT_*`).
+    pub alert_rules: Vec<AlertRule>,
+    /// Ring the terminal bell when an alert fires (`TUITASK_ALERT_BELL`).
+    pub alert_bell: bool,
+    /// Create a TODO for every fired alert (`TUITASK_ALERT_TODO`).
+    pub alert_create_todo: bool,
+    /// Persist system metrics to the database (`TUITASK_METRICS`).
+    pub record_metrics: bool,
+    /// Address of the Prometheus exporter, off when `None`
+    /// (`--metrics-addr` or `TUITASK_METRICS_ADDR`).
+    pub metrics_addr: Option<SocketAddr>,
+    /// Sampling interval of each subsystem (`TUITASK_REFRESH_*_MS`).
+    pub refresh: RefreshRates,
+    /// Weather service (`TUITASK_WEATHER_PROVIDER`); picked from the API key
+    /// when `None`.
+    pub weather_provider: Option<ProviderKind>,
+    /// OpenWeatherMap API key (`WEATHER_API_KEY`).
+    pub weather_api_key: Option<String>,
+    /// Saved locations (`TUITASK_LOCATION`); the public IP is looked up
+    /// only when this is empty.
+    pub locations: Vec<Location>,
+    /// Make no outbound requests at all (`TUITASK_PRIVACY`).
+    pub privacy_mode: bool,
+    /// How often weather is refreshed (`TUITASK_WEATHER_REFRESH_SECS`).
+    pub weather_refresh: Duration,
+    /// How long cached weather is served before it is fetched again on
+    /// startup (`TUITASK_WEATHER_CACHE_TTL_SECS`).
+    pub weather_cache_ttl: Duration,
+    /// Display units and date formats (`TUITASK_*_UNIT`, `TUITASK_*_FORMAT`);
+    /// unset ones follow the locale.
+    pub units: UnitSettings,
+    /// Language of the interface (`TUITASK_LANG`); unset follows the
+    /// `LC_MESSAGES`/`LANG` locale.
+    pub language: Language,
+    /// Timeouts, proxy, user agent and extra root certificates of outbound
+    /// requests (`TUITASK_*_TIMEOUT_SECS`, `HTTPS_PROXY`, `NO_PROXY`, ...).
+    pub http: HttpSettings,
+}
+
+/// Threshold in percent, default duration in seconds and env prefix of
+/// each built-in rule.
+const ALERT_DEFAULTS: [(AlertMetric, &str, f64, u64); 4] = [
+    (AlertMetric::Cpu, "TUITASK_ALERT_CPU", 90.0, 30),
+    (AlertMetric::Memory, "TUITASK_ALERT_RAM", 85.0, 0),
+    (AlertMetric::Disk, "TUITASK_ALERT_DISK", 95.0, 0),
+    (AlertMetric::Load, "TUITASK_ALERT_LOAD", 100.0, 0),
+];
+const ALERT_HYSTERESIS: f64 = 5.0;
+const MIN_REFRESH: Duration = Duration::from_millis(100);
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            history_window: Duration::from_secs(300),
+            alert_rules: ALERT_DEFAULTS
+                .iter()
+                .map(|&(metric, _, threshold, secs)| AlertRule {
+                    metric,
+                    threshold,
+                    duration: Duration::from_secs(secs),
+                    hysteresis: ALERT_HYSTERESIS,
+                })
+                .collect(),
+            alert_bell: false,
+            alert_create_todo: false,
+            record_metrics: true,
+            metrics_addr: None,
+            refresh: RefreshRates::default(),
+            weather_provider: None,
+            weather_api_key: None,
+            locations: Vec::new(),
+            privacy_mode: false,
+            weather_refresh: Duration::from_secs(900),
+            weather_cache_ttl: Duration::from_secs(600),
+            units: UnitSettings::default(),
+            language: Language::default(),
+            http: HttpSettings::default(),
+        }
+    }
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let hysteresis = env_f64("TUITASK_ALERT_HYSTERESIS").unwrap_or(ALERT_HYSTERESIS);
+
+        // `TUITASK_ALERT_CPU=off` (or 0) disables a rule.
+        let alert_rules = ALERT_DEFAULTS
+            .iter()
+            .filter_map(|&(metric, name, threshold, secs)| {
+                let threshold = match env::var(name) {
+                    Ok(value) if value.trim().eq_ignore_ascii_case("off") => return None,
+                    Ok(value) => value.trim().parse::<f64>().unwrap_or(threshold),
+                    Err(_) => threshold,
+                };
+                let duration = env::var(format!("{name}_SECS"))
+                    .ok()
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+                    .map_or(Duration::from_secs(secs), Duration::from_secs);
+
+                (threshold > 0.0).then_some(AlertRule {
+                    metric,
+                    threshold,
+                    duration,
+                    hysteresis,
+                })
+            })
+            .collect();
+
+        Self {
+            history_window: env_secs("TUITASK_HISTORY_SECS").unwrap_or(defaults.history_window),
+            alert_rules,
+            alert_bell: env_bool("TUITASK_ALERT_BELL").unwrap_or(defaults.alert_bell),
+            alert_create_todo: env_bool("TUITASK_ALERT_TODO").unwrap_or(defaults.alert_create_todo),
+            record_metrics: env_bool("TUITASK_METRICS").unwrap_or(defaults.record_metrics),
+            metrics_addr: env::var("TUITASK_METRICS_ADDR")
+                .ok()
+                .and_then(|value| value.trim().parse().ok()),
+            refresh: RefreshRates {
+                system: env_millis("TUITASK_REFRESH_SYSTEM_MS").unwrap_or(defaults.refresh.system),
+                disks: env_millis("TUITASK_REFRESH_DISKS_MS").unwrap_or(defaults.refresh.disks),
+                network: env_millis("TUITASK_REFRESH_NETWORK_MS")
+                    .unwrap_or(defaults.refresh.network),
+                sensors: env_millis("TUITASK_REFRESH_SENSORS_MS")
+                    .unwrap_or(defaults.refresh.sensors),
+                processes: env_millis("TUITASK_REFRESH_PROCESSES_MS")
+                    .unwrap_or(defaults.refresh.processes),
+            },
+            weather_provider: env::var("TUITASK_WEATHER_PROVIDER")
+                .ok()
+                .and_then(|value| ProviderKind::parse(&value)),
+            weather_api_key: env::var("WEATHER_API_KEY")
+                .ok()
+                .filter(|key| !key.trim().is_empty()),
+            locations: env::var("TUITASK_LOCATION")
+                .map(|value| Location::parse_list(&value))
+                .unwrap_or_default(),
+            privacy_mode: env_bool("TUITASK_PRIVACY").unwrap_or(defaults.privacy_mode),
+            weather_refresh: env_secs("TUITASK_WEATHER_REFRESH_SECS")
+                .unwrap_or(defaults.weather_refresh),
+            weather_cache_ttl: env_secs("TUITASK_WEATHER_CACHE_TTL_SECS")
+                .unwrap_or(defaults.weather_cache_ttl),
+            units: units_from_env(),
+            http: http_from_env(),
+            language: env::var("TUITASK_LANG")
+                .ok()
+                .and_then(|value| Language::parse(&value))
+                .unwrap_or_else(|| Language::from_locale(&Locale::from_env("LC_MESSAGES"))),
+        }
+    }
+
+    /// Apply command line flags on top of the environment settings.
+    pub fn apply_args(mut self, args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            match flag.as_str() {
+                "--metrics-addr" => {
+                    let value = inline
+                        .or_else(|| args.next())
+                        .ok_or("--metrics-addr needs an address, e.g. 127.0.0.1:9184")?;
+                    let addr = value
+                        .parse()
+                        .map_err(|_| format!("invalid --metrics-addr '{value}'"))?;
+                    self.metrics_addr = Some(addr);
+                }
+                other => return Err(format!("unknown argument '{other}'")),
+            }
+        }
+        Ok(self)
+    }
+}
+
+fn env_secs(name: &str) -> Option<Duration> {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+}
+
+/// Milliseconds, at least `MIN_REFRESH` so a typo cannot spin the sampler.
+fn env_millis(name: &str) -> Option<Duration> {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(|millis| Duration::from_millis(millis).max(MIN_REFRESH))
+}
+
+fn units_from_env() -> UnitSettings {
+    let locale = UnitSettings::for_locale(
+        &Locale::from_env("LC_MEASUREMENT"),
+        &Locale::from_env("LC_TIME"),
+    );
+    let unit = |name: &str| env::var(name).ok();
+    UnitSettings {
+        temperature: unit("TUITASK_TEMPERATURE_UNIT")
+            .and_then(|value| TemperatureUnit::parse(&value))
+            .unwrap_or(locale.temperature),
+        wind_speed: unit("TUITASK_WIND_UNIT")
+            .and_then(|value| SpeedUnit::parse(&value))
+            .unwrap_or(locale.wind_speed),
+        bytes: unit("TUITASK_BYTE_UNIT")
+            .and_then(|value| ByteUnit::parse(&value))
+            .unwrap_or(locale.bytes),
+        clock_format: env_format("TUITASK_CLOCK_FORMAT").unwrap_or(locale.clock_format),
+        time_format: env_format("TUITASK_TIME_FORMAT").unwrap_or(locale.time_format),
+        datetime_format: env_format("TUITASK_DATETIME_FORMAT").unwrap_or(locale.datetime_format),
+        date_format: env_format("TUITASK_DATE_FORMAT").unwrap_or(locale.date_format),
+    }
+}
+
+fn http_from_env() -> HttpSettings {
+    let defaults = HttpSettings::default();
+    HttpSettings {
+        connect_timeout: env_secs("TUITASK_CONNECT_TIMEOUT_SECS")
+            .unwrap_or(defaults.connect_timeout),
+        read_timeout: env_secs("TUITASK_READ_TIMEOUT_SECS").unwrap_or(defaults.read_timeout),
+        proxy: env_string("HTTPS_PROXY").or_else(|| env_string("https_proxy")),
+        no_proxy: env_string("NO_PROXY").or_else(|| env_string("no_proxy")),
+        user_agent: env_string("TUITASK_USER_AGENT").unwrap_or(defaults.user_agent),
+        ca_bundle: env_string("TUITASK_CA_BUNDLE").map(PathBuf::from),
+    }
+}
+
+fn env_string(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// A strftime format; invalid ones are ignored instead of failing to render.
+fn env_format(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty() && units::is_valid_format(value))
+}
+
+fn env_f64(name: &str) -> Option<f64> {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+fn env_bool(name: &str) -> Option<bool> {
+    env::var(name)
+        .ok()
+        .and_then(|value| match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Some(true),
+            "0" | "false" | "no" | "off" => Some(false),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_metrics_addr_flag() {
+        let expected: SocketAddr = "127.0.0.1:9184".parse().unwrap();
+        for list in [
+            &["--metrics-addr", "127.0.0.1:9184"][..],
+            &["--metrics-addr=127.0.0.1:9184"][..],
+        ] {
+            let config = Config::default().apply_args(args(list)).unwrap();
+            assert_eq!(config.metrics_addr, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_invalid_args() {
+        assert!(Config::default()
+            .apply_args(args(&["--metrics-addr"]))
+            .is_err());
+        assert!(Config::default()
+            .apply_args(args(&["--metrics-addr", "localhost"]))
+            .is_err());
+        assert!(Config::default().apply_args(args(&["--verbose"])).is_err());
+    }
+}
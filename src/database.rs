@@ -1,4 +1,6 @@
-use crate::types::{Status, TODOData};
+use crate::system::metrics::{Metric, Resolution, RESOLUTIONS};
+use crate::types::{Priority, Status, TODOData};
+use chrono::NaiveDate;
 use rusqlite::{Connection, Result as RusqliteResult};
 use std::error::Error;
 use std::fmt;
@@ -26,9 +28,22 @@ pub struct DatabaseManager {
     connection: Option<Connection>,
 }
 
+/// Columns added after the first release, applied to existing databases on open.
+const TODO_MIGRATIONS: &[(&str, &str)] = &[
+    ("priority", "TEXT NOT NULL DEFAULT 'Medium'"),
+    ("due", "TEXT"),
+    ("tags", "TEXT NOT NULL DEFAULT ''"),
+    ("archived", "INTEGER NOT NULL DEFAULT 0"),
+];
+
 impl DatabaseManager {
     pub fn new() -> Result<Self, DatabaseError> {
-        let connection = match Self::open_sqlite_con("data.db") {
+        Self::open("data.db")
+    }
+
+    /// Open (or create) the database at `path`; `":memory:"` gives a throwaway one.
+    pub fn open(path: &str) -> Result<Self, DatabaseError> {
+        let connection = match Self::open_sqlite_con(path) {
             Ok(conn) => {
                 // Create the todos table if it doesn't exist
                 if let Err(e) = conn.execute(
@@ -46,6 +61,35 @@ impl DatabaseManager {
                         e
                     )));
                 }
+                if let Err(e) = conn.execute(
+                    "CREATE TABLE IF NOT EXISTS metrics (
+                        ts INTEGER NOT NULL,
+                        bucket INTEGER NOT NULL,
+                        metric TEXT NOT NULL,
+                        value REAL NOT NULL,
+                        PRIMARY KEY (bucket, metric, ts)
+                    ) WITHOUT ROWID",
+                    [],
+                ) {
+                    return Err(DatabaseError::ConnectionError(format!(
+                        "Error creating metrics table: {}",
+                        e
+                    )));
+                }
+                if let Err(e) = conn.execute(
+                    "CREATE TABLE IF NOT EXISTS weather_cache (
+                        key TEXT PRIMARY KEY,
+                        fetched_at INTEGER NOT NULL,
+                        body TEXT NOT NULL
+                    )",
+                    [],
+                ) {
+                    return Err(DatabaseError::ConnectionError(format!(
+                        "Error creating weather cache table: {}",
+                        e
+                    )));
+                }
+                Self::migrate(&conn)?;
                 Some(conn)
             }
             Err(e) => {
@@ -63,6 +107,29 @@ impl DatabaseManager {
         Connection::open(db_name)
     }
 
+    fn migrate(conn: &Connection) -> Result<(), DatabaseError> {
+        let mut stmt = conn
+            .prepare("SELECT name FROM pragma_table_info('todos')")
+            .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+        let columns = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .and_then(Iterator::collect::<RusqliteResult<Vec<_>>>)
+            .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+
+        for (name, definition) in TODO_MIGRATIONS {
+            if !columns.iter().any(|column| column == name) {
+                conn.execute(
+                    &format!("ALTER TABLE todos ADD COLUMN {name} {definition}"),
+                    [],
+                )
+                .map_err(|e| {
+                    DatabaseError::ConnectionError(format!("Error migrating table: {}", e))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_connection(&self) -> Option<&Connection> {
         self.connection.as_ref()
     }
@@ -71,8 +138,8 @@ impl DatabaseManager {
         if let Some(conn) = &self.connection {
             let mut stmt = conn.prepare(
                 "
-                    SELECT id, title, message, status, date 
-                    FROM todos ORDER BY CASE status
+                    SELECT id, title, message, status, date, priority, due, tags
+                    FROM todos WHERE archived = 0 ORDER BY CASE status
                     WHEN 'Active' THEN 1 WHEN 'Todo' THEN 2 WHEN 'Cancelled' THEN 3 WHEN 'Done' THEN 4 ELSE 5 END, id
                 ",
             ).map_err(|e| DatabaseError::QueryError(e.to_string()))?;
@@ -84,26 +151,32 @@ impl DatabaseManager {
                     let message: String = row.get(2)?;
                     let status_str: String = row.get(3)?;
                     let date_str: String = row.get(4)?;
+                    let priority_str: String = row.get(5)?;
+                    let due_str: Option<String> = row.get(6)?;
+                    let tags_str: String = row.get(7)?;
 
-                    let status = match status_str.as_str() {
-                        "Todo" => Status::Todo,
-                        "Active" => Status::Active,
-                        "Done" => Status::Done,
-                        "Cancelled" => Status::Cancelled,
-                        _ => Status::Todo, // default
-                    };
+                    let status = Status::parse(&status_str).unwrap_or(Status::Todo);
+                    let priority = Priority::parse(&priority_str).unwrap_or_default();
+                    let due =
+                        due_str.and_then(|due| NaiveDate::parse_from_str(&due, "%Y-%m-%d").ok());
 
                     let date = chrono::DateTime::parse_from_rfc3339(&date_str)
                         .unwrap_or_else(|_| chrono::Local::now().into())
                         .with_timezone(&chrono::Local);
 
-                    // Convert String to &'static str by leaking the memory (not ideal but needed for the current struct design)
                     Ok(TODOData {
                         id,
-                        title: Box::leak(title.into_boxed_str()),
-                        message: Box::leak(message.into_boxed_str()),
+                        title,
+                        message,
                         status,
                         date,
+                        priority,
+                        due,
+                        tags: tags_str
+                            .split(',')
+                            .filter(|tag| !tag.is_empty())
+                            .map(str::to_owned)
+                            .collect(),
                     })
                 })
                 .map_err(|e| {
@@ -153,18 +226,11 @@ impl DatabaseManager {
         status: Status,
     ) -> Result<i64, DatabaseError> {
         if let Some(conn) = &self.connection {
-            let status_str = match status {
-                Status::Todo => "Todo",
-                Status::Active => "Active",
-                Status::Done => "Done",
-                Status::Cancelled => "Cancelled",
-            };
-
             let date_str = chrono::Local::now().to_rfc3339();
 
             conn.execute(
                 "INSERT INTO todos (title, message, status, date) VALUES (?1, ?2, ?3, ?4)",
-                [title, message, status_str, &date_str],
+                [title, message, status.as_str(), &date_str],
             )
             .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
 
@@ -178,16 +244,9 @@ impl DatabaseManager {
 
     pub fn update_todo_status(&self, id: i64, status: Status) -> Result<(), DatabaseError> {
         if let Some(conn) = &self.connection {
-            let status_str = match status {
-                Status::Todo => "Todo",
-                Status::Active => "Active",
-                Status::Done => "Done",
-                Status::Cancelled => "Cancelled",
-            };
-
             conn.execute(
                 "UPDATE todos SET status = ?1 WHERE id = ?2",
-                [status_str, &id.to_string()],
+                [status.as_str(), &id.to_string()],
             )
             .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
 
@@ -199,6 +258,246 @@ impl DatabaseManager {
         }
     }
 
+    /// Overwrite every editable field of a TODO.
+    pub fn update_todo(&self, todo: &TODOData) -> Result<(), DatabaseError> {
+        if let Some(conn) = &self.connection {
+            let due = todo.due.map(|due| due.format("%Y-%m-%d").to_string());
+
+            conn.execute(
+                "UPDATE todos SET title = ?1, message = ?2, status = ?3, priority = ?4, due = ?5
+                 WHERE id = ?6",
+                rusqlite::params![
+                    todo.title,
+                    todo.message,
+                    todo.status.as_str(),
+                    todo.priority.as_str(),
+                    due,
+                    todo.id
+                ],
+            )
+            .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+
+            Ok(())
+        } else {
+            Err(DatabaseError::ConnectionError(
+                "Database connection not available".to_string(),
+            ))
+        }
+    }
+
+    /// Set the status of all `ids` in a single transaction.
+    pub fn bulk_update_status(&self, ids: &[i64], status: Status) -> Result<(), DatabaseError> {
+        self.bulk_execute(
+            ids,
+            "UPDATE todos SET status = ?1 WHERE id = ?2",
+            Some(status.as_str()),
+        )
+    }
+
+    /// Delete all `ids` in a single transaction.
+    pub fn bulk_delete(&self, ids: &[i64]) -> Result<(), DatabaseError> {
+        self.bulk_execute(ids, "DELETE FROM todos WHERE id = ?1", None)
+    }
+
+    /// Hide all `ids` from the list without deleting them.
+    pub fn bulk_archive(&self, ids: &[i64]) -> Result<(), DatabaseError> {
+        self.bulk_execute(ids, "UPDATE todos SET archived = 1 WHERE id = ?1", None)
+    }
+
+    /// Add `tag` to all `ids`; tasks that already carry it are left untouched.
+    pub fn bulk_add_tag(&self, ids: &[i64], tag: &str) -> Result<(), DatabaseError> {
+        let tag = tag.trim().replace(',', " ");
+        if tag.is_empty() {
+            return Ok(());
+        }
+        self.bulk_execute(
+            ids,
+            "UPDATE todos SET tags = CASE
+                WHEN tags = '' THEN ?1
+                WHEN instr(',' || tags || ',', ',' || ?1 || ',') > 0 THEN tags
+                ELSE tags || ',' || ?1
+             END
+             WHERE id = ?2",
+            Some(&tag),
+        )
+    }
+
+    /// Run `sql` once per id inside one transaction. The id is bound as the
+    /// last parameter, after `value` when one is given.
+    fn bulk_execute(
+        &self,
+        ids: &[i64],
+        sql: &str,
+        value: Option<&str>,
+    ) -> Result<(), DatabaseError> {
+        if let Some(conn) = &self.connection {
+            let tx = conn
+                .unchecked_transaction()
+                .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+            {
+                let mut stmt = tx
+                    .prepare(sql)
+                    .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+                for id in ids {
+                    match value {
+                        Some(value) => stmt.execute(rusqlite::params![value, id]),
+                        None => stmt.execute([id]),
+                    }
+                    .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+                }
+            }
+            tx.commit()
+                .map_err(|e| DatabaseError::UpdateError(e.to_string()))
+        } else {
+            Err(DatabaseError::ConnectionError(
+                "Database connection not available".to_string(),
+            ))
+        }
+    }
+
+    /// Store one raw sample of every metric taken at Unix time `ts`.
+    pub fn record_metrics(&self, ts: i64, samples: &[(Metric, f64)]) -> Result<(), DatabaseError> {
+        let conn = self.connection()?;
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT OR REPLACE INTO metrics (ts, bucket, metric, value)
+                     VALUES (?1, 0, ?2, ?3)",
+                )
+                .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+            for (metric, value) in samples {
+                stmt.execute(rusqlite::params![ts, metric.as_str(), value])
+                    .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+            }
+        }
+        tx.commit()
+            .map_err(|e| DatabaseError::UpdateError(e.to_string()))
+    }
+
+    /// Average closed windows of each resolution into the next coarser one,
+    /// then delete rows older than their resolution's retention.
+    pub fn rollup_metrics(&self, now: i64) -> Result<(), DatabaseError> {
+        let conn = self.connection()?;
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+
+        for pair in RESOLUTIONS.windows(2) {
+            let (source, target) = (pair[0], pair[1]);
+            // Resume at the newest stored window; REPLACE makes redoing it harmless.
+            let latest: Option<i64> = tx
+                .query_row(
+                    "SELECT MAX(ts) FROM metrics WHERE bucket = ?1",
+                    [target.bucket],
+                    |row| row.get(0),
+                )
+                .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+            let oldest_complete = align_up(now - source.retention, target.bucket);
+            let start = latest.map_or(oldest_complete, |ts| ts.max(oldest_complete));
+            let end = now.div_euclid(target.bucket) * target.bucket;
+
+            tx.execute(
+                "INSERT OR REPLACE INTO metrics (ts, bucket, metric, value)
+                 SELECT (ts / ?1) * ?1, ?1, metric, AVG(value) FROM metrics
+                 WHERE bucket = ?2 AND ts >= ?3 AND ts < ?4
+                 GROUP BY ts / ?1, metric",
+                rusqlite::params![target.bucket, source.bucket, start, end],
+            )
+            .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+        }
+
+        for resolution in RESOLUTIONS {
+            tx.execute(
+                "DELETE FROM metrics WHERE bucket = ?1 AND ts < ?2",
+                rusqlite::params![resolution.bucket, now - resolution.retention],
+            )
+            .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .map_err(|e| DatabaseError::UpdateError(e.to_string()))
+    }
+
+    /// Samples of `metric` since Unix time `from`, oldest first, at the
+    /// resolution suited to the range. Periods not yet rolled up are filled
+    /// in from the finer resolutions.
+    pub fn load_metric_series(
+        &self,
+        metric: Metric,
+        from: i64,
+        now: i64,
+    ) -> Result<Vec<(i64, f64)>, DatabaseError> {
+        let conn = self.connection()?;
+        let coarsest = Resolution::for_range(now - from);
+        let mut stmt = conn
+            .prepare(
+                "SELECT ts, value FROM metrics
+                 WHERE bucket = ?1 AND metric = ?2 AND ts >= ?3
+                 ORDER BY ts",
+            )
+            .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+
+        let mut series = Vec::new();
+        let mut cursor = from;
+        for resolution in RESOLUTIONS
+            .iter()
+            .rev()
+            .skip_while(|resolution| **resolution != coarsest)
+        {
+            let rows = stmt
+                .query_map(
+                    rusqlite::params![resolution.bucket, metric.as_str(), cursor],
+                    |row| Ok((row.get::<_, i64>(0)?, row.get::<_, f64>(1)?)),
+                )
+                .and_then(Iterator::collect::<RusqliteResult<Vec<_>>>)
+                .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+            if let Some((ts, _)) = rows.last() {
+                cursor = ts + resolution.bucket.max(1);
+            }
+            series.extend(rows);
+        }
+        Ok(series)
+    }
+
+    /// Unix time and body of the cached weather stored under `key`.
+    pub fn load_weather_cache(&self, key: &str) -> Result<Option<(i64, String)>, DatabaseError> {
+        let conn = self.connection()?;
+        match conn.query_row(
+            "SELECT fetched_at, body FROM weather_cache WHERE key = ?1",
+            [key],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        ) {
+            Ok(entry) => Ok(Some(entry)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(DatabaseError::QueryError(e.to_string())),
+        }
+    }
+
+    /// Replace the cached weather stored under `key`.
+    pub fn save_weather_cache(
+        &self,
+        key: &str,
+        fetched_at: i64,
+        body: &str,
+    ) -> Result<(), DatabaseError> {
+        let conn = self.connection()?;
+        conn.execute(
+            "INSERT OR REPLACE INTO weather_cache (key, fetched_at, body) VALUES (?1, ?2, ?3)",
+            rusqlite::params![key, fetched_at, body],
+        )
+        .map_err(|e| DatabaseError::UpdateError(e.to_string()))?;
+        Ok(())
+    }
+
+    fn connection(&self) -> Result<&Connection, DatabaseError> {
+        self.connection.as_ref().ok_or_else(|| {
+            DatabaseError::ConnectionError("Database connection not available".to_string())
+        })
+    }
+
     pub fn delete_todo(&self, id: i64) -> Result<(), DatabaseError> {
         if let Some(conn) = &self.connection {
             conn.execute("DELETE FROM todos WHERE id = ?1", [id])
@@ -212,3 +511,162 @@ impl DatabaseManager {
     }
 }
 
+/// Smallest multiple of `step` that is not below `value`.
+fn align_up(value: i64, step: i64) -> i64 {
+    (value + step - 1).div_euclid(step) * step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_todo_round_trip() {
+        let db = DatabaseManager::open(":memory:").unwrap();
+        let id = db.add_todo("Draft", "", Status::Todo).unwrap();
+
+        let mut todo = db.load_todos().unwrap().remove(0);
+        assert_eq!(todo.priority, Priority::Medium);
+        assert_eq!(todo.due, None);
+
+        todo.title = "Final".to_string();
+        todo.message = "line one\nline two".to_string();
+        todo.status = Status::Active;
+        todo.priority = Priority::High;
+        todo.due = NaiveDate::from_ymd_opt(2026, 10, 20);
+        db.update_todo(&todo).unwrap();
+
+        let loaded = db.load_todos().unwrap().remove(0);
+        assert_eq!(loaded.id, id);
+        assert_eq!(loaded.title, "Final");
+        assert_eq!(loaded.message, "line one\nline two");
+        assert_eq!(loaded.status, Status::Active);
+        assert_eq!(loaded.priority, Priority::High);
+        assert_eq!(loaded.due, todo.due);
+    }
+
+    #[test]
+    fn test_bulk_operations() {
+        let db = DatabaseManager::open(":memory:").unwrap();
+        let ids: Vec<i64> = (0..4)
+            .map(|i| db.add_todo(&format!("task {i}"), "", Status::Todo).unwrap())
+            .collect();
+
+        db.bulk_update_status(&ids[..2], Status::Done).unwrap();
+        db.bulk_add_tag(&ids[1..3], "work").unwrap();
+        db.bulk_add_tag(&ids[1..3], "work").unwrap();
+        db.bulk_add_tag(&ids[2..3], "urgent").unwrap();
+        db.bulk_archive(&ids[3..]).unwrap();
+        db.bulk_delete(&ids[..1]).unwrap();
+
+        let todos = db.load_todos().unwrap();
+        let by_id = |id| todos.iter().find(|t| t.id == id).unwrap();
+        assert_eq!(todos.len(), 2);
+        assert_eq!(by_id(ids[1]).status, Status::Done);
+        assert_eq!(by_id(ids[1]).tags, vec!["work"]);
+        assert_eq!(by_id(ids[2]).tags, vec!["work", "urgent"]);
+    }
+
+    #[test]
+    fn test_metrics_rollup_and_retention() {
+        let db = DatabaseManager::open(":memory:").unwrap();
+        let hour = 3600;
+        let now = 100 * hour;
+
+        // Raw samples every 5s over the last 30 minutes, plus one expired sample.
+        for ts in (now - 1800..now).step_by(5) {
+            db.record_metrics(ts, &[(Metric::Cpu, (ts % 60) as f64)])
+                .unwrap();
+        }
+        db.record_metrics(now - 2 * hour, &[(Metric::Cpu, 1.0)])
+            .unwrap();
+
+        db.rollup_metrics(now).unwrap();
+        db.rollup_metrics(now).unwrap();
+
+        let count = |bucket: i64| -> i64 {
+            db.connection()
+                .unwrap()
+                .query_row(
+                    "SELECT COUNT(*) FROM metrics WHERE bucket = ?1",
+                    [bucket],
+                    |row| row.get(0),
+                )
+                .unwrap()
+        };
+        assert_eq!(count(0), 360);
+        assert_eq!(count(60), 30);
+
+        // Each minute holds the average of 0, 5, ..., 55.
+        let minutes = db
+            .load_metric_series(Metric::Cpu, now - 24 * hour, now)
+            .unwrap();
+        assert_eq!(minutes.len(), 30);
+        assert!(minutes
+            .iter()
+            .all(|(_, value)| (*value - 27.5).abs() < 1e-9));
+
+        let raw = db.load_metric_series(Metric::Cpu, now - 600, now).unwrap();
+        assert_eq!(raw.len(), 120);
+    }
+
+    #[test]
+    fn test_metrics_series_falls_back_to_finer_resolution() {
+        let db = DatabaseManager::open(":memory:").unwrap();
+        let now = 1_000_000;
+        db.record_metrics(now - 10, &[(Metric::Memory, 42.0)])
+            .unwrap();
+
+        let series = db
+            .load_metric_series(Metric::Memory, now - 90 * 24 * 3600, now)
+            .unwrap();
+        assert_eq!(series, vec![(now - 10, 42.0)]);
+        assert!(db
+            .load_metric_series(Metric::Cpu, now - 3600, now)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_weather_cache_replaces_entries() {
+        let db = DatabaseManager::open(":memory:").unwrap();
+        assert_eq!(db.load_weather_cache("ip").unwrap(), None);
+
+        db.save_weather_cache("ip", 100, "{}").unwrap();
+        db.save_weather_cache("ip", 200, "{\"a\":1}").unwrap();
+        db.save_weather_cache("city:berlin", 300, "[]").unwrap();
+
+        assert_eq!(
+            db.load_weather_cache("ip").unwrap(),
+            Some((200, "{\"a\":1}".to_string()))
+        );
+        assert_eq!(
+            db.load_weather_cache("city:berlin").unwrap(),
+            Some((300, "[]".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_migration_adds_missing_columns() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE todos (id INTEGER PRIMARY KEY AUTOINCREMENT, title TEXT NOT NULL,
+             message TEXT, status TEXT NOT NULL, date TEXT NOT NULL)",
+            [],
+        )
+        .unwrap();
+
+        DatabaseManager::migrate(&conn).unwrap();
+        DatabaseManager::migrate(&conn).unwrap();
+
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('todos')
+                 WHERE name IN ('priority', 'due', 'tags', 'archived')",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 4);
+    }
+}
@@ -0,0 +1,408 @@
+use std::{fmt::Write as _, io, time::Duration};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::watch,
+};
+
+use crate::{
+    logger,
+    types::{Status, SystemData, TODOData},
+};
+
+const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+/// Requests larger than this are rejected; scrapers send a few hundred bytes.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+/// Connections that do not send a complete request within this time are
+/// answered with 408 and closed.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Everything the exporter publishes, refreshed by the app every tick.
+#[derive(Debug, Clone, Default)]
+pub struct ExporterSnapshot {
+    pub system: SystemData,
+    /// Number of (non-archived) TODOs per status, in `Status::ALL` order.
+    pub todo_counts: Vec<(Status, usize)>,
+}
+
+impl ExporterSnapshot {
+    pub fn new(system: &SystemData, todos: &[TODOData]) -> Self {
+        Self {
+            system: system.clone(),
+            todo_counts: Status::ALL
+                .iter()
+                .map(|status| {
+                    let count = todos.iter().filter(|todo| todo.status == *status).count();
+                    (*status, count)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Accept scrapes on `listener` until the snapshot sender is dropped.
+pub async fn serve(listener: TcpListener, snapshot: watch::Receiver<ExporterSnapshot>) {
+    let mut updates = snapshot.clone();
+    let sender_dropped = async move { while updates.changed().await.is_ok() {} };
+    tokio::pin!(sender_dropped);
+
+    loop {
+        let (stream, _) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    let _ = logger::error(format!("Metrics exporter accept failed: {e}"));
+                    continue;
+                }
+            },
+            () = &mut sender_dropped => return,
+        };
+
+        let snapshot = snapshot.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &snapshot, REQUEST_TIMEOUT).await {
+                let _ = logger::error(format!("Metrics exporter request failed: {e}"));
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    snapshot: &watch::Receiver<ExporterSnapshot>,
+    timeout: Duration,
+) -> io::Result<()> {
+    let Ok(request) = tokio::time::timeout(timeout, read_request(&mut stream)).await else {
+        return respond(
+            &mut stream,
+            "408 Request Timeout",
+            "text/plain",
+            "request timeout\n",
+        )
+        .await;
+    };
+    let request = request?;
+
+    let request = String::from_utf8_lossy(&request);
+    let mut parts = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let (method, path) = (parts.next(), parts.next());
+
+    let (status, content_type, body) = match (method, path) {
+        (Some("GET"), Some("/metrics")) => {
+            let body = render_metrics(&snapshot.borrow());
+            ("200 OK", CONTENT_TYPE, body)
+        }
+        (Some("GET"), Some("/")) => (
+            "200 OK",
+            "text/plain; charset=utf-8",
+            "tuitask exporter\nMetrics are served at /metrics\n".to_string(),
+        ),
+        (Some("GET"), _) => ("404 Not Found", "text/plain", "not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_string(),
+        ),
+    };
+
+    respond(&mut stream, status, content_type, &body).await
+}
+
+/// Read up to the end of the request headers, or `MAX_REQUEST_SIZE`.
+async fn read_request(stream: &mut TcpStream) -> io::Result<Vec<u8>> {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+        if request.len() > MAX_REQUEST_SIZE {
+            break;
+        }
+    }
+    Ok(request)
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Render the snapshot in the Prometheus text exposition format.
+pub fn render_metrics(snapshot: &ExporterSnapshot) -> String {
+    let data = &snapshot.system;
+    let mut out = Exposition::default();
+
+    out.gauge(
+        "tuitask_cpu_usage_percent",
+        "Global CPU usage.",
+        &[(vec![], f64::from(data.cpu.usage))],
+    );
+    out.gauge(
+        "tuitask_cpu_core_usage_percent",
+        "CPU usage per core.",
+        &data
+            .cpu
+            .cores
+            .iter()
+            .enumerate()
+            .map(|(core, usage)| (vec![("core", core.to_string())], f64::from(*usage)))
+            .collect::<Vec<_>>(),
+    );
+    out.gauge(
+        "tuitask_memory_total_bytes",
+        "Total memory.",
+        &[(vec![], data.total_memory * BYTES_PER_GB)],
+    );
+    out.gauge(
+        "tuitask_memory_used_bytes",
+        "Used memory.",
+        &[(vec![], data.used_memory * BYTES_PER_GB)],
+    );
+    out.gauge(
+        "tuitask_memory_available_bytes",
+        "Available memory.",
+        &[(vec![], data.available_memory * BYTES_PER_GB)],
+    );
+    out.gauge(
+        "tuitask_swap_total_bytes",
+        "Total swap.",
+        &[(vec![], data.swap_total * BYTES_PER_GB)],
+    );
+    out.gauge(
+        "tuitask_swap_used_bytes",
+        "Used swap.",
+        &[(vec![], data.swap_used * BYTES_PER_GB)],
+    );
+    if let Some(container) = &data.container {
+        let samples =
+            |value: Option<f64>| value.map(|v| (vec![], v)).into_iter().collect::<Vec<_>>();
+        out.gauge(
+            "tuitask_cgroup_memory_limit_bytes",
+            "Memory limit of the cgroup tuitask runs in.",
+            &samples(container.memory_limit.map(|gb| gb * BYTES_PER_GB)),
+        );
+        out.gauge(
+            "tuitask_cgroup_cpu_quota_cores",
+            "CPU quota of the cgroup tuitask runs in, in cores.",
+            &samples(container.cpu_quota),
+        );
+    }
+    out.gauge(
+        "tuitask_load_average",
+        "Load average.",
+        &[
+            (vec![("period", "1m".to_string())], data.host.load_one),
+            (vec![("period", "5m".to_string())], data.host.load_five),
+            (vec![("period", "15m".to_string())], data.host.load_fifteen),
+        ],
+    );
+    out.gauge(
+        "tuitask_uptime_seconds",
+        "Seconds since boot.",
+        &[(vec![], data.host.uptime as f64)],
+    );
+
+    let disk_labels = |disk: &crate::types::DiskInfo| {
+        vec![
+            ("mount", disk.mount_point.clone()),
+            ("device", disk.name.clone()),
+            ("fstype", disk.file_system.clone()),
+        ]
+    };
+    let disks = |value: fn(&crate::types::DiskInfo) -> f64| {
+        data.disks
+            .iter()
+            .map(|disk| (disk_labels(disk), value(disk)))
+            .collect::<Vec<_>>()
+    };
+    out.gauge(
+        "tuitask_disk_total_bytes",
+        "Filesystem size.",
+        &disks(|disk| disk.total as f64),
+    );
+    out.gauge(
+        "tuitask_disk_available_bytes",
+        "Filesystem space available.",
+        &disks(|disk| disk.available as f64),
+    );
+    out.gauge(
+        "tuitask_disk_read_bytes_per_second",
+        "Disk read throughput.",
+        &disks(|disk| disk.read_rate),
+    );
+    out.gauge(
+        "tuitask_disk_write_bytes_per_second",
+        "Disk write throughput.",
+        &disks(|disk| disk.write_rate),
+    );
+
+    let networks = |value: fn(&crate::types::NetworkInfo) -> f64| {
+        data.networks
+            .iter()
+            .map(|net| (vec![("interface", net.name.clone())], value(net)))
+            .collect::<Vec<_>>()
+    };
+    out.counter(
+        "tuitask_network_receive_bytes_total",
+        "Bytes received.",
+        &networks(|net| net.total_received as f64),
+    );
+    out.counter(
+        "tuitask_network_transmit_bytes_total",
+        "Bytes transmitted.",
+        &networks(|net| net.total_transmitted as f64),
+    );
+    out.counter(
+        "tuitask_network_receive_errors_total",
+        "Receive errors.",
+        &networks(|net| net.rx_errors as f64),
+    );
+    out.counter(
+        "tuitask_network_transmit_errors_total",
+        "Transmit errors.",
+        &networks(|net| net.tx_errors as f64),
+    );
+
+    out.gauge(
+        "tuitask_sensor_temperature_celsius",
+        "Hardware sensor temperature.",
+        &data
+            .sensors
+            .iter()
+            .filter_map(|sensor| {
+                sensor
+                    .temperature
+                    .map(|temp| (vec![("sensor", sensor.label.clone())], f64::from(temp)))
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    out.gauge(
+        "tuitask_todos",
+        "TODO items per status, archived items excluded.",
+        &snapshot
+            .todo_counts
+            .iter()
+            .map(|(status, count)| (vec![("status", status.as_str().to_string())], *count as f64))
+            .collect::<Vec<_>>(),
+    );
+
+    out.text
+}
+
+type Labels = Vec<(&'static str, String)>;
+
+#[derive(Default)]
+struct Exposition {
+    text: String,
+}
+
+impl Exposition {
+    fn gauge(&mut self, name: &str, help: &str, samples: &[(Labels, f64)]) {
+        self.family(name, help, "gauge", samples);
+    }
+
+    fn counter(&mut self, name: &str, help: &str, samples: &[(Labels, f64)]) {
+        self.family(name, help, "counter", samples);
+    }
+
+    fn family(&mut self, name: &str, help: &str, kind: &str, samples: &[(Labels, f64)]) {
+        if samples.is_empty() {
+            return;
+        }
+        let _ = writeln!(self.text, "# HELP {name} {help}");
+        let _ = writeln!(self.text, "# TYPE {name} {kind}");
+        for (labels, value) in samples {
+            self.text.push_str(name);
+            if !labels.is_empty() {
+                let labels: Vec<String> = labels
+                    .iter()
+                    .map(|(key, value)| format!("{key}=\"{}\"", escape_label(value)))
+                    .collect();
+                let _ = write!(self.text, "{{{}}}", labels.join(","));
+            }
+            let _ = writeln!(self.text, " {}", format_value(*value));
+        }
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_idle_connection_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (_tx, snapshot) = watch::channel(ExporterSnapshot::default());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            handle_connection(stream, &snapshot, Duration::from_millis(100)).await
+        });
+
+        // Connect and send nothing.
+        let mut client = TcpStream::connect(addr).await.unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"));
+        server.await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_render_metrics_format() {
+        let mut snapshot = ExporterSnapshot::default();
+        snapshot.system.cpu.usage = 12.5;
+        snapshot.system.cpu.cores = vec![10.0, 15.0];
+        snapshot.todo_counts = vec![(Status::Todo, 3), (Status::Done, 1)];
+
+        let text = render_metrics(&snapshot);
+        assert!(text
+            .contains("# TYPE tuitask_cpu_usage_percent gauge\ntuitask_cpu_usage_percent 12.5\n"));
+        assert!(text.contains("tuitask_cpu_core_usage_percent{core=\"1\"} 15\n"));
+        assert!(text.contains("tuitask_todos{status=\"Todo\"} 3\n"));
+        // Families without samples are left out entirely.
+        assert!(!text.contains("tuitask_disk_total_bytes"));
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+        assert_eq!(format_value(f64::INFINITY), "+Inf");
+    }
+}
@@ -0,0 +1,219 @@
+//! Translations of user-visible strings. Messages are written in English in
+//! the code and double as lookup keys; other languages map them in a
+//! catalog, and anything missing there falls back to English.
+
+mod ru;
+
+use std::{fmt::Display, sync::OnceLock};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::units::Locale;
+
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Russian,
+}
+
+impl Language {
+    /// A language code or name (`ru`, `russian`) or a locale such as
+    /// `ru_RU.UTF-8`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "english" => Some(Language::English),
+            "russian" | "русский" => Some(Language::Russian),
+            value => Self::for_code(Locale::parse(value).language()),
+        }
+    }
+
+    /// Language of the user's locale, English when it is not translated.
+    pub fn from_locale(locale: &Locale) -> Self {
+        Self::for_code(locale.language()).unwrap_or_default()
+    }
+
+    fn for_code(code: &str) -> Option<Self> {
+        match code {
+            "en" => Some(Language::English),
+            "ru" => Some(Language::Russian),
+            _ => None,
+        }
+    }
+
+    fn translate(self, msgid: &'static str) -> &'static str {
+        match self {
+            Language::English => msgid,
+            Language::Russian => ru::catalog().get(msgid).copied().unwrap_or(msgid),
+        }
+    }
+
+    fn plural(self, one: &'static str, other: &'static str, n: u64) -> String {
+        let translated = self.translate(other);
+        let (forms, form) = if translated == other {
+            (vec![one, other], Language::English.plural_form(n))
+        } else {
+            (translated.split('|').collect(), self.plural_form(n))
+        };
+        let template = forms.get(form).or(forms.last()).copied().unwrap_or(other);
+        fill(template, &[&n])
+    }
+
+    /// Which of the language's plural forms goes with `n`: one/other in
+    /// English, one/few/many in Russian.
+    fn plural_form(self, n: u64) -> usize {
+        match self {
+            Language::English => usize::from(n != 1),
+            Language::Russian => match (n % 10, n % 100) {
+                (1, rem) if rem != 11 => 0,
+                (2..=4, rem) if !(12..=14).contains(&rem) => 1,
+                _ => 2,
+            },
+        }
+    }
+}
+
+/// Make `language` the one used by [`t`]. Only the first call has an
+/// effect; until then English is used.
+pub fn init(language: Language) {
+    let _ = LANGUAGE.set(language);
+}
+
+pub fn language() -> Language {
+    LANGUAGE.get().copied().unwrap_or_default()
+}
+
+/// Translation of `msgid` in the current language.
+pub fn t(msgid: &'static str) -> &'static str {
+    language().translate(msgid)
+}
+
+/// Translation of `msgid` with its `{}` placeholders replaced by `args` in
+/// order.
+pub fn tf(msgid: &'static str, args: &[&dyn Display]) -> String {
+    fill(t(msgid), args)
+}
+
+/// `n` with the plural form that fits it, e.g. `tn("{} task", "{} tasks", 3)`.
+/// A translation of `other` lists all forms of its language separated by `|`.
+pub fn tn(one: &'static str, other: &'static str, n: u64) -> String {
+    language().plural(one, other, n)
+}
+
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut args = args.iter();
+    let mut pieces = template.split("{}");
+    let mut text = pieces.next().unwrap_or_default().to_string();
+    for piece in pieces {
+        if let Some(arg) = args.next() {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(piece);
+    }
+    text
+}
+
+/// Columns taken by `text` on screen, for sizing layouts around
+/// translated strings.
+pub fn width(text: &str) -> u16 {
+    u16::try_from(text.width()).unwrap_or(u16::MAX)
+}
+
+/// `min` columns, widened to fit the translation of `msgid`, e.g. for a
+/// table column and its header.
+pub fn fit(msgid: &'static str, min: u16) -> u16 {
+    min.max(width(t(msgid)))
+}
+
+/// Columns taken by the widest translation of `msgids`.
+pub fn max_width(msgids: &[&'static str]) -> u16 {
+    msgids
+        .iter()
+        .map(|msgid| width(t(msgid)))
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_selection() {
+        assert_eq!(Language::parse("ru"), Some(Language::Russian));
+        assert_eq!(Language::parse("ru_RU.UTF-8"), Some(Language::Russian));
+        assert_eq!(Language::parse("English"), Some(Language::English));
+        assert_eq!(Language::parse("de"), None);
+        assert_eq!(
+            Language::from_locale(&Locale::parse("de_DE.UTF-8")),
+            Language::English
+        );
+        assert_eq!(
+            Language::from_locale(&Locale::parse("ru_UA")),
+            Language::Russian
+        );
+    }
+
+    #[test]
+    fn test_translate_and_fill() {
+        assert_eq!(t("Add some =]"), "Add some =]");
+        assert_eq!(Language::Russian.translate("Are you sure?"), "Вы уверены?");
+        assert_eq!(
+            Language::Russian.translate("no such message"),
+            "no such message"
+        );
+        assert_eq!(tf("Delete {} tasks?", &[&3]), "Delete 3 tasks?");
+        assert_eq!(fill("{} of {}", &[&1]), "1 of ");
+        assert_eq!(max_width(&["Host: ", "Kernel: "]), 8);
+    }
+
+    #[test]
+    fn test_plural_forms() {
+        assert_eq!(tn("{} task", "{} tasks", 1), "1 task");
+        assert_eq!(tn("{} task", "{} tasks", 11), "11 tasks");
+        assert_eq!(
+            Language::Russian.plural("{} task", "{} tasks", 22),
+            "22 задачи"
+        );
+        assert_eq!(
+            Language::Russian.plural("{} task", "{} tasks", 11),
+            "11 задач"
+        );
+
+        let russian: Vec<usize> = [1, 2, 5, 11, 12, 21, 22, 25, 101, 111]
+            .map(|n| Language::Russian.plural_form(n))
+            .to_vec();
+        assert_eq!(russian, [0, 1, 2, 2, 2, 0, 1, 2, 0, 2]);
+    }
+
+    #[test]
+    fn test_russian_catalog_is_consistent() {
+        let mut seen = std::collections::HashSet::new();
+        for (msgid, translation) in ru::MESSAGES {
+            assert!(seen.insert(msgid), "duplicate message {msgid:?}");
+            assert!(!translation.is_empty(), "empty translation of {msgid:?}");
+            // Plural forms are separated by `|` and each has the placeholders.
+            for form in translation.split('|') {
+                assert_eq!(
+                    msgid.matches("{}").count(),
+                    form.matches("{}").count(),
+                    "placeholders differ for {msgid:?}"
+                );
+            }
+            // Padding and separators are part of the layout, so they must
+            // survive translation.
+            assert_eq!(
+                msgid.starts_with(' '),
+                translation.starts_with(' '),
+                "leading space differs for {msgid:?}"
+            );
+            assert_eq!(
+                msgid.ends_with(' '),
+                translation.ends_with(' '),
+                "trailing space differs for {msgid:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+/// Russian translations, keyed by the English message.
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // Views and panel titles
+    ("Tasks", "Задачи"),
+    ("Disks", "Диски"),
+    ("Network", "Сеть"),
+    ("Processes", "Процессы"),
+    ("Sensors", "Датчики"),
+    ("Alerts", "Оповещения"),
+    ("History", "История"),
+    ("[== TODOS PANEL ==]", "[== ЗАДАЧИ ==]"),
+    ("[== ALERTS ", "[== ОПОВЕЩЕНИЯ "),
+    ("[== ALERTS ==]", "[== ОПОВЕЩЕНИЯ ==]"),
+    ("[== DISKS ==]", "[== ДИСКИ ==]"),
+    ("[== HISTORY ==]", "[== ИСТОРИЯ ==]"),
+    ("[== NETWORK ==]", "[== СЕТЬ ==]"),
+    ("[== PROCESSES ==]", "[== ПРОЦЕССЫ ==]"),
+    ("[== SENSORS ==]", "[== ДАТЧИКИ ==]"),
+    ("[== WEATHER ==]", "[== ПОГОДА ==]"),
+    // Tasks
+    ("Todo", "Новая"),
+    ("Active", "В работе"),
+    ("Done", "Готово"),
+    ("Cancelled", "Отменена"),
+    ("Low", "Низкий"),
+    ("Medium", "Средний"),
+    ("High", "Высокий"),
+    (" VISUAL", " ВЫДЕЛЕНИЕ"),
+    (" {} marked", " отмечено: {}"),
+    ("Add some =]", "Добавьте задачу =]"),
+    ("Title: {}", "Название: {}"),
+    ("Status: ", "Статус: "),
+    ("  Priority: ", "  Приоритет: "),
+    ("  Due: ", "  Срок: "),
+    ("Tags: ", "Метки: "),
+    ("↓ more · PgUp/PgDn", "↓ ещё · PgUp/PgDn"),
+    ("↑ PgUp", "↑ PgUp"),
+    (" ADD NEW TODO ", " НОВАЯ ЗАДАЧА "),
+    ("TITLE", "НАЗВАНИЕ"),
+    ("Enter title...", "Введите название..."),
+    ("MESSAGE", "ОПИСАНИЕ"),
+    (
+        "Enter description... (Markdown, Enter for new line)",
+        "Введите описание... (Markdown, Enter для новой строки)",
+    ),
+    (" Save ", " Сохранить "),
+    (" Switch ", " Переключить "),
+    (" Cancel ", " Отмена "),
+    ("(max {} chars)", "(не более {} символов)"),
+    (" TAG 1 TASK ", " МЕТКА ДЛЯ 1 ЗАДАЧИ "),
+    (" TAG {} TASKS ", " МЕТКА ДЛЯ ЗАДАЧ: {} "),
+    ("tag name", "название метки"),
+    ("Apply ", "Применить "),
+    ("Cancel", "Отмена"),
+    ("{} tasks", "{} задача|{} задачи|{} задач"),
+    ("Marked {} as {}", "{}: статус «{}»"),
+    ("Deleted {}", "Удалено: {}"),
+    ("Archived {}", "В архиве: {}"),
+    ("Tagged {} #{}", "{}: метка #{}"),
+    ("Saved \"{}\"", "Сохранено: «{}»"),
+    ("Not saved: {}", "Не сохранено: {}"),
+    (
+        "Not saved: {}. Press E to fix the draft ({})",
+        "Не сохранено: {}. Нажмите E, чтобы исправить черновик ({})",
+    ),
+    ("Cannot write draft: {}", "Не удалось записать черновик: {}"),
+    ("Cannot launch '{}': {}", "Не удалось запустить «{}»: {}"),
+    (
+        "Editor exited with an error, changes discarded",
+        "Редактор завершился с ошибкой, изменения отброшены",
+    ),
+    // Confirmations
+    ("Delete this task?", "Удалить эту задачу?"),
+    ("Delete {} tasks?", "Удалить задачи ({} шт.)?"),
+    ("Send {}?", "Отправить {}?"),
+    (" Y/N", " Y/N"),
+    ("✖ Delete", "✖ Удаление"),
+    ("Archive this task?", "Архивировать эту задачу?"),
+    ("Archive {} tasks?", "Архивировать задачи ({} шт.)?"),
+    ("▣ Archive", "▣ Архив"),
+    ("ESC (Cancel)", "ESC (Отмена)"),
+    ("⚠ Exit", "⚠ Выход"),
+    ("Are you sure?", "Вы уверены?"),
+    ("Terminal too small", "Терминал слишком мал"),
+    ("Current: ", "Сейчас: "),
+    ("Required: ", "Нужно: "),
+    // Header
+    (
+        "Press `Ctrl-C` or `q` to stop running.",
+        "Нажмите `Ctrl-C` или `q` для выхода.",
+    ),
+    (" ?: Toggle help menu", " ?: Справка"),
+    ("CPU: ", "ЦП: "),
+    ("RAM: ", "ОЗУ: "),
+    ("  Load: ", "  Нагрузка: "),
+    ("  ?: help", "  ?: справка"),
+    ("Cores: ", "Ядра: "),
+    ("Frequency: ", "Частота: "),
+    ("Usage: ", "Загрузка: "),
+    (" of host, ", " хоста, "),
+    (" of quota", " квоты"),
+    ("Total: ", "Всего: "),
+    ("Used: ", "Занято: "),
+    ("Available: ", "Доступно: "),
+    ("Swap: ", "Подкачка: "),
+    ("Load: ", "Нагрузка: "),
+    ("Uptime: ", "Время работы: "),
+    ("Host: ", "Хост: "),
+    ("  i: details", "  i: подробнее"),
+    ("unknown", "неизвестно"),
+    ("  OS: ", "  ОС: "),
+    ("  Kernel: ", "  Ядро: "),
+    ("Booted: ", "Запущен: "),
+    ("  Uptime: ", "  Время работы: "),
+    ("  Load 1/5/15m: ", "  Нагрузка 1/5/15м: "),
+    (" ({} cores)", " (ядер: {})"),
+    // Weather
+    ("Forecast not loaded yet", "Прогноз ещё не загружен"),
+    (" No weather data", " Нет данных о погоде"),
+    ("Hour", "Час"),
+    ("Day", "День"),
+    ("Temp", "Темп."),
+    ("Rain", "Осадки"),
+    ("Feels like", "Ощущается"),
+    ("Humidity", "Влажность"),
+    ("Wind", "Ветер"),
+    ("Pressure", "Давление"),
+    (" · {} ago", " · {} назад"),
+    (" retry in {}s · r", " повтор через {}с · r"),
+    ("Privacy mode", "Приватный режим"),
+    ("Offline", "Нет сети"),
+    ("Loading ", "Загрузка "),
+    ("{}d {}h", "{}д {}ч"),
+    ("{}h {}m", "{}ч {}м"),
+    ("{}m", "{}м"),
+    ("{}s", "{}с"),
+    ("Refreshing weather…", "Обновление погоды…"),
+    // Alerts
+    ("{} firing", "активно: {}"),
+    (
+        "All alert rules are disabled",
+        "Все правила оповещений отключены",
+    ),
+    ("Metric", "Метрика"),
+    ("Rule", "Правило"),
+    ("Current", "Текущее"),
+    ("State", "Состояние"),
+    ("ok", "норма"),
+    ("pending {}s", "ожидание {}с"),
+    ("FIRING {}s", "АКТИВНО {}с"),
+    (" Log ", " Журнал "),
+    ("No alerts yet", "Оповещений пока нет"),
+    ("Disk", "Диск"),
+    ("Load", "Нагрузка"),
+    // Disks
+    (
+        "No mounted filesystems reported",
+        "Нет смонтированных файловых систем",
+    ),
+    ("Mount", "Точка"),
+    ("Device", "Устройство"),
+    ("FS", "ФС"),
+    ("Size", "Размер"),
+    ("Used", "Занято"),
+    ("Avail", "Свободно"),
+    ("Use%", "Исп.%"),
+    ("Read", "Чтение"),
+    ("Write", "Запись"),
+    // History
+    ("CPU", "ЦП"),
+    ("RAM", "ОЗУ"),
+    ("Swap", "Подкачка"),
+    ("none", "нет"),
+    ("peak", "пик"),
+    ("CPU usage", "Загрузка ЦП"),
+    ("RAM usage", "Использование ОЗУ"),
+    ("Swap usage", "Использование подкачки"),
+    ("Fullest disk", "Самый заполненный диск"),
+    ("Load (1m)", "Нагрузка (1м)"),
+    ("Network RX", "Сеть, приём"),
+    ("Network TX", "Сеть, передача"),
+    ("1h", "1ч"),
+    ("6h", "6ч"),
+    ("24h", "24ч"),
+    ("7d", "7д"),
+    ("30d", "30д"),
+    ("90d", "90д"),
+    (" min ", " мин "),
+    ("  avg ", "  сред "),
+    ("  max ", "  макс "),
+    ("  ({} samples)", "  (замеров: {})"),
+    (
+        " No samples recorded for this range yet",
+        " За этот период замеров пока нет",
+    ),
+    // Network
+    (" Public IP: ", " Внешний IP: "),
+    ("   Interfaces: ", "   Интерфейсы: "),
+    ("   Errors: ", "   Ошибки: "),
+    (" peak {}", " пик {}"),
+    ("No network interfaces reported", "Нет сетевых интерфейсов"),
+    ("Interface", "Интерфейс"),
+    ("Addresses", "Адреса"),
+    ("RX/s", "Приём/с"),
+    ("TX/s", "Передача/с"),
+    ("Received", "Принято"),
+    ("Sent", "Передано"),
+    ("Errors", "Ошибки"),
+    // Processes
+    ("Sort: ", "Сортировка: "),
+    ("  Tree", "  Дерево"),
+    (
+        "type to filter, Enter to keep, Esc to clear",
+        "введите фильтр, Enter — оставить, Esc — сбросить",
+    ),
+    ("filter", "фильтр"),
+    ("No processes reported", "Нет данных о процессах"),
+    (
+        "No processes match the filter",
+        "Нет процессов по этому фильтру",
+    ),
+    ("PID", "PID"),
+    ("Name", "Имя"),
+    ("User", "Пользователь"),
+    ("CPU%", "ЦП%"),
+    ("Memory", "Память"),
+    ("Command", "Команда"),
+    ("Sent {} to {} ({})", "{} отправлен процессу {} ({})"),
+    // Sensors
+    ("No sensors available", "Датчики недоступны"),
+    (
+        "This machine does not expose temperature sensors (common in VMs and containers).",
+        "Эта машина не предоставляет датчики температуры (обычно так в ВМ и контейнерах).",
+    ),
+    ("Sensor", "Датчик"),
+    ("Max", "Макс."),
+    ("Critical", "Критич."),
+    // Help
+    (" HELP ", " СПРАВКА "),
+    (" to close help", " — закрыть справку"),
+    (" NAVIGATION ", " НАВИГАЦИЯ "),
+    (" TASK ACTIONS ", " ДЕЙСТВИЯ С ЗАДАЧАМИ "),
+    (" MULTI-SELECT ", " МНОЖЕСТВЕННЫЙ ВЫБОР "),
+    (" INPUT MODAL ", " ОКНО ВВОДА "),
+    (" PROCESSES ", " ПРОЦЕССЫ "),
+    (" HISTORY ", " ИСТОРИЯ "),
+    (" SYSTEM CONTROLS ", " УПРАВЛЕНИЕ "),
+    (" - Add new task", " - Добавить задачу"),
+    (" - Cancel and close modal", " - Отменить и закрыть окно"),
+    (
+        " - Change sort column / invert order",
+        " - Сменить столбец сортировки / обратить порядок",
+    ),
+    (" - Choose the charted metric", " - Выбрать метрику графика"),
+    (" - Clear marks", " - Снять отметки"),
+    (
+        " - Delete selected/marked tasks",
+        " - Удалить выбранные/отмеченные задачи",
+    ),
+    (
+        " - Delete word / to line start",
+        " - Удалить слово / до начала строки",
+    ),
+    (
+        " - Edit task in $VISUAL / $EDITOR",
+        " - Редактировать задачу в $VISUAL / $EDITOR",
+    ),
+    (
+        " - Filter by name, user, command or PID",
+        " - Фильтр по имени, пользователю, команде или PID",
+    ),
+    (
+        " - Mark task / mark a range",
+        " - Отметить задачу / отметить диапазон",
+    ),
+    (
+        " - Move cursor (Alt+B/F by word)",
+        " - Переместить курсор (Alt+B/F по словам)",
+    ),
+    (" - Move down in list", " - Вниз по списку"),
+    (" - Move up in list", " - Вверх по списку"),
+    (
+        " - New line in description (Markdown)",
+        " - Новая строка в описании (Markdown)",
+    ),
+    (" - Refresh weather now", " - Обновить погоду сейчас"),
+    (
+        " - Save task (Enter in title field)",
+        " - Сохранить задачу (Enter в поле названия)",
+    ),
+    (
+        " - Select a row in the system views",
+        " - Выбрать строку в системных вкладках",
+    ),
+    (
+        " - Send SIGTERM / SIGKILL (asks first)",
+        " - Отправить SIGTERM / SIGKILL (с подтверждением)",
+    ),
+    (
+        " - Set status Todo/Active/Done/Cancelled",
+        " - Статус Новая/В работе/Готово/Отменена",
+    ),
+    (
+        " - Shorter / longer time range (1h to 90d)",
+        " - Короче / длиннее период (от 1ч до 90д)",
+    ),
+    (" - Show quit confirmation", " - Подтверждение выхода"),
+    (" - Show this help menu", " - Показать эту справку"),
+    (
+        " - Switch between title/message fields",
+        " - Переключить поле названия/описания",
+    ),
+    (" - Switch between views", " - Переключить вкладку"),
+    (
+        " - Switch to the next saved location",
+        " - Следующее сохранённое место",
+    ),
+    (
+        " - Tag / archive marked tasks",
+        " - Пометить / архивировать отмеченные задачи",
+    ),
+    (
+        " - Toggle CPU/RAM/swap history",
+        " - Показать историю ЦП/ОЗУ/подкачки",
+    ),
+    (
+        " - Toggle host details (OS, kernel, boot time, load)",
+        " - Сведения о хосте (ОС, ядро, время запуска, нагрузка)",
+    ),
+    (" - Toggle task status", " - Сменить статус задачи"),
+    (
+        " - Scroll the task description",
+        " - Прокрутить описание задачи",
+    ),
+    (" - Toggle tree mode", " - Режим дерева"),
+    (
+        " - Toggle weather panel / hourly or daily forecast",
+        " - Панель погоды / прогноз по часам или дням",
+    ),
+];
+
+pub(super) fn catalog() -> &'static HashMap<&'static str, &'static str> {
+    static CATALOG: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    CATALOG.get_or_init(|| MESSAGES.iter().copied().collect())
+}
@@ -1,6 +1,12 @@
 pub mod app;
 pub mod components;
+pub mod config;
 pub mod database;
+pub mod exporter;
+pub mod http;
+pub mod i18n;
 pub mod logger;
 pub mod system;
 pub mod types;
+pub mod units;
+pub mod weather;
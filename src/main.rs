@@ -1,6 +1,6 @@
 #![warn(clippy::all, clippy::pedantic)]
 use tokio::runtime::Runtime;
-use tuitask::{app::App, logger};
+use tuitask::{app::App, config::Config, i18n, logger, units};
 
 fn main() -> color_eyre::Result<()> {
     logger::init("logs/app.log")?;
@@ -12,8 +12,13 @@ fn main() -> color_eyre::Result<()> {
     let runtime = Runtime::new()?;
     let handle = runtime.handle().clone();
 
+    let config = Config::from_env()
+        .apply_args(std::env::args().skip(1))
+        .map_err(|e| color_eyre::eyre::eyre!(e))?;
+    units::init(config.units.clone());
+    i18n::init(config.language);
     let terminal = ratatui::init();
-    let result = App::new(handle).run(terminal);
+    let result = App::new(handle, config).run(terminal);
 
     if let Err(ref error) = result {
         let _ = logger::error(format!("Application error: {error}"));
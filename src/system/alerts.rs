@@ -0,0 +1,285 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local};
+
+use crate::types::SystemData;
+
+/// Number of alert events kept for the log view.
+pub const ALERT_LOG_LEN: usize = 100;
+
+/// Resource an alert rule watches. Every metric is expressed in percent;
+/// load is the 1-minute load average relative to the number of cores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertMetric {
+    Cpu,
+    Memory,
+    Disk,
+    Load,
+}
+
+impl AlertMetric {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AlertMetric::Cpu => "CPU",
+            AlertMetric::Memory => "RAM",
+            AlertMetric::Disk => "Disk",
+            AlertMetric::Load => "Load",
+        }
+    }
+
+    /// Current value in percent and a short description of it.
+    fn measure(self, data: &SystemData) -> Option<(f64, String)> {
+        match self {
+            AlertMetric::Cpu => {
+                let usage = f64::from(data.cpu.usage);
+                Some((usage, format!("CPU at {usage:.0}%")))
+            }
+            AlertMetric::Memory => Some((
+                data.usage_memory,
+                format!("RAM at {:.0}%", data.usage_memory),
+            )),
+            AlertMetric::Disk => data
+                .disks
+                .iter()
+                .max_by(|a, b| a.usage_percent().total_cmp(&b.usage_percent()))
+                .map(|disk| {
+                    let usage = disk.usage_percent();
+                    (usage, format!("Disk {} at {usage:.0}%", disk.mount_point))
+                }),
+            AlertMetric::Load => {
+                let cores = data.cpu.len.max(1);
+                let load = data.host.load_one;
+                Some((
+                    load / cores as f64 * 100.0,
+                    format!("Load {load:.2} on {cores} cores"),
+                ))
+            }
+        }
+    }
+}
+
+/// Fire when `metric` stays above `threshold` for `duration`; resolve once it
+/// drops below `threshold - hysteresis`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    pub metric: AlertMetric,
+    pub threshold: f64,
+    pub duration: Duration,
+    pub hysteresis: f64,
+}
+
+impl AlertRule {
+    pub fn describe(&self) -> String {
+        if self.duration.is_zero() {
+            format!("> {:.0}%", self.threshold)
+        } else {
+            format!("> {:.0}% for {}s", self.threshold, self.duration.as_secs())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertState {
+    Normal,
+    /// Above the threshold since the given instant, not long enough to fire.
+    Pending(Instant),
+    Firing(Instant),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertEventKind {
+    Fired,
+    Resolved,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlertEvent {
+    pub metric: AlertMetric,
+    pub kind: AlertEventKind,
+    pub message: String,
+    pub at: DateTime<Local>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleStatus {
+    pub rule: AlertRule,
+    pub state: AlertState,
+    /// Latest measured value in percent, if the metric is available.
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlertEngine {
+    pub rules: Vec<RuleStatus>,
+    pub log: VecDeque<AlertEvent>,
+}
+
+impl AlertEngine {
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        Self {
+            rules: rules
+                .into_iter()
+                .map(|rule| RuleStatus {
+                    rule,
+                    state: AlertState::Normal,
+                    value: None,
+                })
+                .collect(),
+            log: VecDeque::with_capacity(ALERT_LOG_LEN),
+        }
+    }
+
+    pub fn firing_count(&self) -> usize {
+        self.rules
+            .iter()
+            .filter(|status| matches!(status.state, AlertState::Firing(_)))
+            .count()
+    }
+
+    /// Feed a new sample. Returns the alerts that fired or resolved with it,
+    /// which are also appended to the log.
+    pub fn evaluate(&mut self, data: &SystemData, now: Instant) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+
+        for status in &mut self.rules {
+            let Some((value, description)) = status.rule.metric.measure(data) else {
+                status.value = None;
+                continue;
+            };
+            status.value = Some(value);
+
+            let rule = &status.rule;
+            let (state, kind) = match status.state {
+                AlertState::Normal | AlertState::Pending(_) if value <= rule.threshold => {
+                    (AlertState::Normal, None)
+                }
+                AlertState::Normal if rule.duration.is_zero() => {
+                    (AlertState::Firing(now), Some(AlertEventKind::Fired))
+                }
+                AlertState::Normal => (AlertState::Pending(now), None),
+                AlertState::Pending(since) if now.duration_since(since) >= rule.duration => {
+                    (AlertState::Firing(now), Some(AlertEventKind::Fired))
+                }
+                AlertState::Firing(_) if value < rule.threshold - rule.hysteresis => {
+                    (AlertState::Normal, Some(AlertEventKind::Resolved))
+                }
+                state => (state, None),
+            };
+            status.state = state;
+
+            if let Some(kind) = kind {
+                let message = match kind {
+                    AlertEventKind::Fired => format!("{description} ({})", rule.describe()),
+                    AlertEventKind::Resolved => format!("{description}, back to normal"),
+                };
+                events.push(AlertEvent {
+                    metric: rule.metric,
+                    kind,
+                    message,
+                    at: Local::now(),
+                });
+            }
+        }
+
+        for event in &events {
+            if self.log.len() == ALERT_LOG_LEN {
+                self.log.pop_front();
+            }
+            self.log.push_back(event.clone());
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cpu_rule(duration: u64) -> AlertRule {
+        AlertRule {
+            metric: AlertMetric::Cpu,
+            threshold: 90.0,
+            duration: Duration::from_secs(duration),
+            hysteresis: 5.0,
+        }
+    }
+
+    fn sample(cpu: f32) -> SystemData {
+        let mut data = SystemData::default();
+        data.cpu.usage = cpu;
+        data
+    }
+
+    fn kinds(events: &[AlertEvent]) -> Vec<AlertEventKind> {
+        events.iter().map(|event| event.kind).collect()
+    }
+
+    #[test]
+    fn test_fires_only_after_duration() {
+        let mut engine = AlertEngine::new(vec![cpu_rule(30)]);
+        let start = Instant::now();
+
+        assert!(engine.evaluate(&sample(95.0), start).is_empty());
+        assert!(engine
+            .evaluate(&sample(95.0), start + Duration::from_secs(20))
+            .is_empty());
+        let events = engine.evaluate(&sample(95.0), start + Duration::from_secs(30));
+        assert_eq!(kinds(&events), vec![AlertEventKind::Fired]);
+        assert_eq!(engine.firing_count(), 1);
+        assert!(events[0].message.contains("CPU at 95%"));
+    }
+
+    #[test]
+    fn test_dip_below_threshold_resets_pending() {
+        let mut engine = AlertEngine::new(vec![cpu_rule(30)]);
+        let start = Instant::now();
+
+        engine.evaluate(&sample(95.0), start);
+        engine.evaluate(&sample(50.0), start + Duration::from_secs(20));
+        let events = engine.evaluate(&sample(95.0), start + Duration::from_secs(40));
+        assert!(events.is_empty());
+        assert!(matches!(engine.rules[0].state, AlertState::Pending(_)));
+    }
+
+    #[test]
+    fn test_hysteresis_delays_resolve() {
+        let mut engine = AlertEngine::new(vec![cpu_rule(0)]);
+        let now = Instant::now();
+
+        assert_eq!(
+            kinds(&engine.evaluate(&sample(92.0), now)),
+            vec![AlertEventKind::Fired]
+        );
+        // Below the threshold but inside the hysteresis band: still firing.
+        assert!(engine.evaluate(&sample(87.0), now).is_empty());
+        assert_eq!(engine.firing_count(), 1);
+        assert_eq!(
+            kinds(&engine.evaluate(&sample(84.0), now)),
+            vec![AlertEventKind::Resolved]
+        );
+        assert_eq!(engine.log.len(), 2);
+    }
+
+    #[test]
+    fn test_load_is_relative_to_cores() {
+        let rule = AlertRule {
+            metric: AlertMetric::Load,
+            threshold: 100.0,
+            duration: Duration::ZERO,
+            hysteresis: 10.0,
+        };
+        let mut engine = AlertEngine::new(vec![rule]);
+        let mut data = SystemData::default();
+        data.cpu.len = 4;
+        data.host.load_one = 3.5;
+        assert!(engine.evaluate(&data, Instant::now()).is_empty());
+
+        data.host.load_one = 4.4;
+        let events = engine.evaluate(&data, Instant::now());
+        assert_eq!(kinds(&events), vec![AlertEventKind::Fired]);
+        assert!(events[0].message.starts_with("Load 4.40 on 4 cores"));
+    }
+}
@@ -0,0 +1,385 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// cgroup v1 reports "no limit" as a huge page-aligned number.
+const V1_UNLIMITED: u64 = 1 << 62;
+
+/// Resource limits of the cgroup this process runs in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CgroupLimits {
+    pub version: u8,
+    /// Memory limit in bytes, `None` when unlimited.
+    pub memory_limit: Option<u64>,
+    /// Memory in use by the cgroup in bytes, without reclaimable page cache.
+    pub memory_used: Option<u64>,
+    /// CPU quota in cores, `None` when unlimited.
+    pub cpu_quota: Option<f64>,
+}
+
+impl CgroupLimits {
+    pub fn is_limited(&self) -> bool {
+        self.memory_limit.is_some() || self.cpu_quota.is_some()
+    }
+}
+
+/// Directories of the cgroup this process runs in. Finding them walks the
+/// hierarchy, so it is done once; limits and usage are then read from them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cgroup {
+    version: u8,
+    /// Mount root and own directory of the memory controller.
+    memory: Option<(PathBuf, PathBuf)>,
+    /// Mount root and own directory of the cpu controller.
+    cpu: Option<(PathBuf, PathBuf)>,
+    /// Cumulative CPU time counter: `cpu.stat` (v2) or `cpuacct.usage` (v1).
+    cpu_usage: Option<PathBuf>,
+}
+
+/// Locate the cgroup described by `self_cgroup` (the contents of
+/// `/proc/self/cgroup`) in the hierarchy mounted at `root`.
+pub fn locate(root: &Path, self_cgroup: &str) -> Option<Cgroup> {
+    let entries = parse_self_cgroup(self_cgroup);
+
+    if root.join("cgroup.controllers").is_file() {
+        let path = entries
+            .iter()
+            .find(|(id, controllers, _)| id == "0" && controllers.is_empty())
+            .map_or("/", |(_, _, path)| path.as_str());
+        let dir = cgroup_dir(root, path);
+        return Some(Cgroup {
+            version: 2,
+            memory: Some((root.to_path_buf(), dir.clone())),
+            cpu: Some((root.to_path_buf(), dir.clone())),
+            cpu_usage: Some(dir.join("cpu.stat")),
+        });
+    }
+
+    let path_for = |controller: &str| {
+        entries
+            .iter()
+            .find(|(_, controllers, _)| controllers.iter().any(|c| c == controller))
+            .map_or("/", |(_, _, path)| path.as_str())
+    };
+    let controller = |names: &[&str], name: &str| {
+        names
+            .iter()
+            .map(|dir| root.join(dir))
+            .find(|dir| dir.is_dir())
+            .map(|mount| {
+                let dir = cgroup_dir(&mount, path_for(name));
+                (mount, dir)
+            })
+    };
+
+    let memory = controller(&["memory"], "memory");
+    let cpu = controller(&["cpu,cpuacct", "cpu"], "cpu");
+    if memory.is_none() && cpu.is_none() {
+        return None;
+    }
+    let cpu_usage = controller(&["cpu,cpuacct", "cpuacct"], "cpuacct")
+        .map(|(_, dir)| dir.join("cpuacct.usage"));
+
+    Some(Cgroup {
+        version: 1,
+        memory,
+        cpu,
+        cpu_usage,
+    })
+}
+
+/// Read the limits of the cgroup described by `self_cgroup` from the
+/// hierarchy mounted at `root`.
+pub fn detect(root: &Path, self_cgroup: &str) -> Option<CgroupLimits> {
+    locate(root, self_cgroup).map(|cgroup| cgroup.limits())
+}
+
+impl Cgroup {
+    /// Current limits. The tightest limit along the path up to the mount root
+    /// wins, as the kernel enforces.
+    pub fn limits(&self) -> CgroupLimits {
+        let memory_limit = self.memory.as_ref().and_then(|(root, dir)| {
+            if self.version == 2 {
+                tightest(root, dir, |dir| read_max(&dir.join("memory.max")))
+            } else {
+                tightest(root, dir, |dir| {
+                    read_u64(&dir.join("memory.limit_in_bytes"))
+                        .filter(|limit| *limit < V1_UNLIMITED)
+                })
+            }
+        });
+        let cpu_quota = self.cpu.as_ref().and_then(|(root, dir)| {
+            if self.version == 2 {
+                tightest_f64(root, dir, |dir| {
+                    read(&dir.join("cpu.max")).and_then(|text| parse_cpu_max(&text))
+                })
+            } else {
+                tightest_f64(root, dir, |dir| {
+                    let quota = read(&dir.join("cpu.cfs_quota_us"))?;
+                    let period = read(&dir.join("cpu.cfs_period_us"))?;
+                    parse_v1_quota(&quota, &period)
+                })
+            }
+        });
+
+        CgroupLimits {
+            version: self.version,
+            memory_limit,
+            memory_used: self.memory_used(),
+            cpu_quota,
+        }
+    }
+
+    /// Memory in use by the cgroup in bytes, without reclaimable page cache.
+    pub fn memory_used(&self) -> Option<u64> {
+        let (_, dir) = self.memory.as_ref()?;
+        let (usage, inactive) = if self.version == 2 {
+            ("memory.current", "inactive_file")
+        } else {
+            ("memory.usage_in_bytes", "total_inactive_file")
+        };
+        read_u64(&dir.join(usage)).map(|usage| {
+            let inactive = read(&dir.join("memory.stat"))
+                .and_then(|stat| stat_value(&stat, inactive))
+                .unwrap_or(0);
+            usage.saturating_sub(inactive)
+        })
+    }
+
+    /// CPU time used by the cgroup since it was created, in microseconds.
+    pub fn cpu_usage_usec(&self) -> Option<u64> {
+        let path = self.cpu_usage.as_ref()?;
+        if self.version == 2 {
+            read(path).and_then(|stat| stat_value(&stat, "usage_usec"))
+        } else {
+            read_u64(path).map(|nanos| nanos / 1000)
+        }
+    }
+}
+
+/// Percent of `quota` cores used, given `used_usec` of CPU time spent by the
+/// cgroup during `elapsed` wall time.
+pub fn quota_usage(used_usec: u64, elapsed: Duration, quota: f64) -> Option<f32> {
+    let available = elapsed.as_micros() as f64 * quota;
+    (available > 0.0).then(|| (used_usec as f64 / available * 100.0).clamp(0.0, 100.0) as f32)
+}
+
+/// Name of the container runtime, if `self_cgroup` or marker files under
+/// `fs_root` show that we run inside one.
+pub fn container_runtime(fs_root: &Path, self_cgroup: &str) -> Option<&'static str> {
+    const MARKERS: [(&str, &str); 5] = [
+        ("kubepods", "kubernetes"),
+        ("docker", "docker"),
+        ("libpod", "podman"),
+        ("containerd", "containerd"),
+        ("lxc", "lxc"),
+    ];
+
+    if let Some((_, name)) = MARKERS
+        .iter()
+        .find(|(marker, _)| self_cgroup.contains(marker))
+    {
+        return Some(name);
+    }
+    if fs_root.join(".dockerenv").exists() {
+        Some("docker")
+    } else if fs_root.join("run/.containerenv").exists() {
+        Some("podman")
+    } else {
+        None
+    }
+}
+
+/// Lines of `/proc/self/cgroup` as (hierarchy id, controllers, path).
+fn parse_self_cgroup(text: &str) -> Vec<(String, Vec<String>, String)> {
+    text.lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ':');
+            let id = parts.next()?.to_string();
+            let controllers = parts
+                .next()?
+                .split(',')
+                .filter(|c| !c.is_empty())
+                .map(str::to_string)
+                .collect();
+            let path = parts.next()?.to_string();
+            Some((id, controllers, path))
+        })
+        .collect()
+}
+
+/// Directory of `path` below the mount `root`. Inside a cgroup namespace the
+/// path may not exist below the mount, which is then the process's own cgroup.
+fn cgroup_dir(root: &Path, path: &str) -> PathBuf {
+    let dir = root.join(path.trim_start_matches('/'));
+    if dir.is_dir() {
+        dir
+    } else {
+        root.to_path_buf()
+    }
+}
+
+fn tightest(root: &Path, dir: &Path, read: impl Fn(&Path) -> Option<u64>) -> Option<u64> {
+    dir.ancestors()
+        .take_while(|ancestor| ancestor.starts_with(root))
+        .filter_map(read)
+        .min()
+}
+
+fn tightest_f64(root: &Path, dir: &Path, read: impl Fn(&Path) -> Option<f64>) -> Option<f64> {
+    dir.ancestors()
+        .take_while(|ancestor| ancestor.starts_with(root))
+        .filter_map(read)
+        .min_by(f64::total_cmp)
+}
+
+fn read(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok()
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    read(path)?.trim().parse().ok()
+}
+
+/// A v2 limit file: a number of bytes or `max`.
+fn read_max(path: &Path) -> Option<u64> {
+    let text = read(path)?;
+    match text.trim() {
+        "max" => None,
+        value => value.parse().ok(),
+    }
+}
+
+/// `cpu.max` holds "$QUOTA $PERIOD" in microseconds, quota may be `max`.
+fn parse_cpu_max(text: &str) -> Option<f64> {
+    let mut parts = text.split_whitespace();
+    let quota = parts.next()?.parse::<f64>().ok()?;
+    let period = parts.next().map_or(Some(100_000.0), |p| p.parse().ok())?;
+    (quota > 0.0 && period > 0.0).then(|| quota / period)
+}
+
+/// v1 quota is -1 when unlimited.
+fn parse_v1_quota(quota: &str, period: &str) -> Option<f64> {
+    let quota = quota.trim().parse::<i64>().ok().filter(|q| *q > 0)?;
+    let period = period.trim().parse::<i64>().ok().filter(|p| *p > 0)?;
+    Some(quota as f64 / period as f64)
+}
+
+fn stat_value(stat: &str, key: &str) -> Option<u64> {
+    stat.lines().find_map(|line| {
+        let (name, value) = line.split_once(' ')?;
+        (name == key).then(|| value.trim().parse().ok()).flatten()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: u64 = 1024 * 1024;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/cgroup")
+            .join(name)
+    }
+
+    #[test]
+    fn test_v2_limits() {
+        let limits = detect(&fixture("v2"), "0::/\n").unwrap();
+        assert_eq!(
+            limits,
+            CgroupLimits {
+                version: 2,
+                memory_limit: Some(512 * MB),
+                memory_used: Some(192 * MB),
+                cpu_quota: Some(1.5),
+            }
+        );
+        assert!(limits.is_limited());
+    }
+
+    #[test]
+    fn test_v2_unlimited() {
+        let limits = detect(&fixture("v2_unlimited"), "0::/\n").unwrap();
+        assert_eq!(limits.memory_limit, None);
+        assert_eq!(limits.cpu_quota, None);
+        assert_eq!(limits.memory_used, Some(100 * MB));
+        assert!(!limits.is_limited());
+    }
+
+    #[test]
+    fn test_v2_limit_inherited_from_parent_slice() {
+        let limits = detect(&fixture("v2_nested"), "0::/system.slice/app.service\n").unwrap();
+        assert_eq!(limits.memory_limit, Some(1024 * MB));
+        assert_eq!(limits.cpu_quota, Some(0.5));
+        assert_eq!(limits.memory_used, Some(200 * MB));
+    }
+
+    #[test]
+    fn test_v1_limits_with_namespaced_paths() {
+        let self_cgroup = "12:pids:/docker/abc\n4:memory:/docker/abc\n2:cpu,cpuacct:/docker/abc\n";
+        let limits = detect(&fixture("v1"), self_cgroup).unwrap();
+        assert_eq!(
+            limits,
+            CgroupLimits {
+                version: 1,
+                memory_limit: Some(1024 * MB),
+                memory_used: Some(384 * MB),
+                cpu_quota: Some(2.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_cpu_usage_counters() {
+        let v2 = locate(&fixture("v2"), "0::/\n").unwrap();
+        assert_eq!(v2.cpu_usage_usec(), Some(7_500_000));
+
+        let self_cgroup = "4:memory:/docker/abc\n2:cpu,cpuacct:/docker/abc\n";
+        let v1 = locate(&fixture("v1"), self_cgroup).unwrap();
+        assert_eq!(v1.cpu_usage_usec(), Some(3_000_000));
+
+        // Half a core used for a second against a quota of 1.5 cores.
+        let usage = quota_usage(500_000, Duration::from_secs(1), 1.5).unwrap();
+        assert!((usage - 33.33).abs() < 0.01);
+        assert_eq!(
+            quota_usage(5_000_000, Duration::from_secs(1), 1.5),
+            Some(100.0)
+        );
+        assert_eq!(quota_usage(1, Duration::ZERO, 1.5), None);
+    }
+
+    #[test]
+    fn test_missing_hierarchy() {
+        assert_eq!(detect(&fixture("does-not-exist"), "0::/\n"), None);
+    }
+
+    #[test]
+    fn test_parsers() {
+        assert_eq!(parse_cpu_max("max 100000"), None);
+        assert_eq!(parse_cpu_max("50000 100000\n"), Some(0.5));
+        assert_eq!(parse_v1_quota("-1", "100000"), None);
+        assert_eq!(
+            stat_value("file 1\ninactive_file 42\n", "inactive_file"),
+            Some(42)
+        );
+        assert_eq!(stat_value("active_file 1\n", "inactive_file"), None);
+    }
+
+    #[test]
+    fn test_container_runtime() {
+        let root = fixture("v2");
+        assert_eq!(
+            container_runtime(&root, "0::/kubepods/besteffort/pod1\n"),
+            Some("kubernetes")
+        );
+        assert_eq!(
+            container_runtime(&root, "4:memory:/docker/abc\n"),
+            Some("docker")
+        );
+        assert_eq!(container_runtime(&root, "0::/user.slice\n"), None);
+    }
+}
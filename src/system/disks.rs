@@ -0,0 +1,59 @@
+use std::time::Instant;
+
+use sysinfo::Disks;
+
+use crate::types::DiskInfo;
+
+/// Mounted filesystems with read/write throughput computed between refreshes.
+#[derive(Debug)]
+pub struct DiskCollector {
+    disks: Disks,
+    last_refresh: Instant,
+}
+
+impl DiskCollector {
+    pub fn new() -> Self {
+        Self {
+            disks: Disks::new_with_refreshed_list(),
+            last_refresh: Instant::now(),
+        }
+    }
+
+    pub fn collect(&mut self) -> Vec<DiskInfo> {
+        self.disks.refresh(true);
+        let elapsed = self.last_refresh.elapsed().as_secs_f64().max(f64::EPSILON);
+        self.last_refresh = Instant::now();
+
+        let mut disks: Vec<DiskInfo> = self
+            .disks
+            .list()
+            .iter()
+            .map(|disk| {
+                let usage = disk.usage();
+                let total = disk.total_space();
+                let available = disk.available_space();
+                DiskInfo {
+                    name: disk.name().to_string_lossy().into_owned(),
+                    mount_point: disk.mount_point().to_string_lossy().into_owned(),
+                    file_system: disk.file_system().to_string_lossy().into_owned(),
+                    total,
+                    available,
+                    used: total.saturating_sub(available),
+                    read_rate: usage.read_bytes as f64 / elapsed,
+                    write_rate: usage.written_bytes as f64 / elapsed,
+                    is_removable: disk.is_removable(),
+                }
+            })
+            .filter(|disk| disk.total > 0)
+            .collect();
+
+        disks.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+        disks
+    }
+}
+
+impl Default for DiskCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,353 @@
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus},
+};
+
+use chrono::NaiveDate;
+
+use crate::types::{Priority, Status, TODOData};
+
+const DELIMITER: &str = "---";
+const DUE_FORMAT: &str = "%Y-%m-%d";
+
+/// Fields of a TODO as written in the editor file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TodoDocument {
+    pub title: String,
+    pub status: Status,
+    pub priority: Priority,
+    pub due: Option<NaiveDate>,
+    pub body: String,
+}
+
+impl TodoDocument {
+    pub fn from_todo(todo: &TODOData) -> Self {
+        Self {
+            title: todo.title.clone(),
+            status: todo.status,
+            priority: todo.priority,
+            due: todo.due,
+            body: todo.message.clone(),
+        }
+    }
+
+    pub fn apply_to(self, todo: &mut TODOData) {
+        todo.title = self.title;
+        todo.status = self.status;
+        todo.priority = self.priority;
+        todo.due = self.due;
+        todo.message = self.body;
+    }
+
+    /// Serialize as a front-matter header followed by the Markdown body.
+    pub fn render(&self) -> String {
+        let due = self
+            .due
+            .map(|due| due.format(DUE_FORMAT).to_string())
+            .unwrap_or_default();
+        let statuses: Vec<&str> = Status::ALL.iter().map(Status::as_str).collect();
+        let priorities: Vec<&str> = Priority::ALL.iter().map(Priority::as_str).collect();
+
+        format!(
+            "{DELIMITER}\n\
+             title: {}\n\
+             status: {}\n\
+             priority: {}\n\
+             due: {due}\n\
+             {DELIMITER}\n\
+             \n\
+             {}\n\
+             \n\
+             # status: {} | priority: {} | due: YYYY-MM-DD or empty\n",
+            self.title,
+            self.status.as_str(),
+            self.priority.as_str(),
+            self.body,
+            statuses.join(", "),
+            priorities.join(", "),
+        )
+    }
+
+    /// Parse the editor file back. Errors carry a human readable reason.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+        if lines.next().map(str::trim) != Some(DELIMITER) {
+            return Err(format!("file must start with a '{DELIMITER}' line"));
+        }
+
+        let mut title = None;
+        let mut status = None;
+        let mut priority = None;
+        let mut due = None;
+        let mut closed = false;
+
+        for (number, line) in lines.by_ref().enumerate() {
+            if line.trim() == DELIMITER {
+                closed = true;
+                break;
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("header line {}: expected 'key: value'", number + 2))?;
+            let value = value.trim();
+
+            match key.trim().to_ascii_lowercase().as_str() {
+                "title" => title = Some(value.to_owned()),
+                "status" => {
+                    status = Some(
+                        Status::parse(value).ok_or_else(|| format!("unknown status '{value}'"))?,
+                    )
+                }
+                "priority" => {
+                    priority = Some(
+                        Priority::parse(value)
+                            .ok_or_else(|| format!("unknown priority '{value}'"))?,
+                    )
+                }
+                "due" if value.is_empty() => due = None,
+                "due" => {
+                    due = Some(
+                        NaiveDate::parse_from_str(value, DUE_FORMAT)
+                            .map_err(|_| format!("due date '{value}' is not YYYY-MM-DD"))?,
+                    )
+                }
+                other => return Err(format!("unknown header field '{other}'")),
+            }
+        }
+
+        if !closed {
+            return Err(format!("header is not closed with '{DELIMITER}'"));
+        }
+
+        let title = title
+            .filter(|title| !title.is_empty())
+            .ok_or_else(|| "title must not be empty".to_string())?;
+
+        let body: Vec<&str> = lines.collect();
+        let body = strip_hint(&body).join("\n");
+
+        Ok(Self {
+            title,
+            status: status.unwrap_or(Status::Todo),
+            priority: priority.unwrap_or_default(),
+            due,
+            body: body.trim_matches('\n').to_owned(),
+        })
+    }
+}
+
+/// Drop the trailing `# status: ...` hint line written by [`TodoDocument::render`].
+fn strip_hint<'a>(lines: &'a [&'a str]) -> &'a [&'a str] {
+    match lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map(|i| (i, lines[i]))
+    {
+        Some((i, line)) if line.starts_with("# status: ") => &lines[..i],
+        _ => lines,
+    }
+}
+
+/// Per-user directory for drafts, readable only by the owner.
+fn drafts_dir() -> io::Result<PathBuf> {
+    let dir = directories::ProjectDirs::from("", "", "tuitask")
+        .map(|dirs| dirs.data_local_dir().join("drafts"))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+    fs::create_dir_all(&dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(dir)
+}
+
+/// Drafts kept after a failed parse or save, one per TODO. Editing a TODO
+/// again continues from its own draft, and editing another TODO in between
+/// leaves it alone.
+#[derive(Debug, Default)]
+pub struct Drafts {
+    /// Where new drafts go, the per-user drafts directory when `None`.
+    dir: Option<PathBuf>,
+    kept: HashMap<i64, PathBuf>,
+}
+
+impl Drafts {
+    /// The kept draft of TODO `id` if it is still on disk, otherwise a new one
+    /// written from `document`.
+    pub fn open(&mut self, id: i64, document: &TodoDocument) -> io::Result<PathBuf> {
+        match self.kept.remove(&id) {
+            Some(path) if path.exists() => Ok(path),
+            _ => {
+                let dir = match &self.dir {
+                    Some(dir) => dir.clone(),
+                    None => drafts_dir()?,
+                };
+                create_draft_in(&dir, id, document)
+            }
+        }
+    }
+
+    /// Keep the draft of TODO `id` for its next edit.
+    pub fn keep(&mut self, id: i64, path: PathBuf) {
+        self.kept.insert(id, path);
+    }
+}
+
+/// The file is created exclusively with mode 0600, so an existing file or
+/// symlink at the chosen name is never followed; another name is tried.
+fn create_draft_in(dir: &Path, id: i64, document: &TodoDocument) -> io::Result<PathBuf> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    for attempt in 0..100 {
+        let path = dir.join(format!("tuitask-{id}-{}-{attempt}.md", std::process::id()));
+        match options.open(&path) {
+            Ok(mut file) => {
+                file.write_all(document.render().as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "no free draft file name",
+    ))
+}
+
+/// The editor to launch: `$VISUAL`, then `$EDITOR`, then a platform default.
+pub fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        })
+}
+
+/// Run the editor on `path` and wait for it. The command may carry arguments
+/// (e.g. `code --wait`).
+pub fn launch(path: &Path) -> io::Result<ExitStatus> {
+    let command = editor_command();
+    let mut parts = command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no editor configured"))?;
+
+    Command::new(program).args(parts).arg(path).status()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document() -> TodoDocument {
+        TodoDocument {
+            title: "Write report".to_string(),
+            status: Status::Active,
+            priority: Priority::High,
+            due: NaiveDate::from_ymd_opt(2026, 11, 2),
+            body: "## Notes\n- [ ] outline\n\nsee https://example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_parse_round_trip() {
+        let doc = document();
+        assert_eq!(TodoDocument::parse(&doc.render()), Ok(doc));
+    }
+
+    #[test]
+    fn test_parse_is_lenient_about_case_and_missing_fields() {
+        let parsed = TodoDocument::parse("---\nTitle: Call Bob\nSTATUS: done\n---\nhi\n").unwrap();
+        assert_eq!(parsed.title, "Call Bob");
+        assert_eq!(parsed.status, Status::Done);
+        assert_eq!(parsed.priority, Priority::Medium);
+        assert_eq!(parsed.due, None);
+        assert_eq!(parsed.body, "hi");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("title: x\n", "must start"),
+            ("---\ntitle: x\n", "not closed"),
+            ("---\ntitle:\n---\n", "title must not be empty"),
+            ("---\ntitle: x\nstatus: later\n---\n", "unknown status"),
+            ("---\ntitle: x\ndue: tomorrow\n---\n", "YYYY-MM-DD"),
+            ("---\ntitle: x\ncolor: red\n---\n", "unknown header field"),
+            ("---\ntitle x\n---\n", "key: value"),
+        ];
+        for (input, expected) in cases {
+            let err = TodoDocument::parse(input).unwrap_err();
+            assert!(err.contains(expected), "{input:?} gave {err:?}");
+        }
+    }
+
+    #[test]
+    fn test_drafts_are_kept_per_todo() {
+        let dir = env::temp_dir().join(format!("tuitask-kept-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut drafts = Drafts {
+            dir: Some(dir.clone()),
+            ..Drafts::default()
+        };
+
+        // A's draft failed to parse and is kept, then B is edited.
+        let a = drafts.open(1, &document()).unwrap();
+        fs::write(&a, "title: broken").unwrap();
+        drafts.keep(1, a.clone());
+        let b = drafts.open(2, &document()).unwrap();
+        assert_ne!(a, b);
+        fs::remove_file(&b).unwrap();
+
+        assert_eq!(fs::read_to_string(&a).unwrap(), "title: broken");
+        assert_eq!(drafts.open(1, &document()).unwrap(), a);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_create_draft_never_reuses_a_file() {
+        let dir = env::temp_dir().join(format!("tuitask-drafts-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let taken = dir.join(format!("tuitask-7-{}-0.md", std::process::id()));
+        fs::write(&taken, "someone else's").unwrap();
+
+        let path = create_draft_in(&dir, 7, &document()).unwrap();
+        assert_ne!(path, taken);
+        assert_eq!(fs::read_to_string(&taken).unwrap(), "someone else's");
+        assert_eq!(
+            TodoDocument::parse(&fs::read_to_string(&path).unwrap()),
+            Ok(document())
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,133 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::{system::sampler::Subsystems, types::SystemData};
+
+/// Fixed-capacity FIFO: pushing into a full buffer drops the oldest sample.
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    capacity: usize,
+    items: VecDeque<T>,
+}
+
+impl<T: Copy> RingBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            items: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.items.len() == self.capacity {
+            self.items.pop_front();
+        }
+        self.items.push_back(value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn latest(&self) -> Option<T> {
+        self.items.back().copied()
+    }
+
+    /// Samples from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + '_ {
+        self.items.iter().copied()
+    }
+
+    /// The newest `n` samples, oldest first.
+    pub fn last_n(&self, n: usize) -> impl Iterator<Item = T> + '_ {
+        self.items
+            .iter()
+            .skip(self.items.len().saturating_sub(n))
+            .copied()
+    }
+}
+
+/// Rolling window of samples, one per tick. Utilization is in percent,
+/// network throughput in bytes per second summed over all interfaces.
+#[derive(Debug, Clone)]
+pub struct MetricsHistory {
+    pub cpu: RingBuffer<f64>,
+    pub memory: RingBuffer<f64>,
+    pub swap: RingBuffer<f64>,
+    pub rx: RingBuffer<f64>,
+    pub tx: RingBuffer<f64>,
+}
+
+impl MetricsHistory {
+    /// Keep enough samples to cover `window` at one sample per `tick`.
+    pub fn new(window: Duration, tick: Duration) -> Self {
+        let capacity = (window.as_millis() / tick.as_millis().max(1)) as usize;
+        Self {
+            cpu: RingBuffer::new(capacity),
+            memory: RingBuffer::new(capacity),
+            swap: RingBuffer::new(capacity),
+            rx: RingBuffer::new(capacity),
+            tx: RingBuffer::new(capacity),
+        }
+    }
+
+    /// Record the parts of `data` that were `refreshed` in this sample. A
+    /// subsystem that was not sampled, e.g. the network while its view is
+    /// hidden, adds nothing instead of repeating its last value.
+    pub fn push(&mut self, data: &SystemData, refreshed: Subsystems) {
+        if refreshed.contains(Subsystems::SYSTEM) {
+            self.cpu.push(f64::from(data.cpu.usage));
+            self.memory.push(data.usage_memory);
+            self.swap.push(data.swap_usage());
+        }
+        if refreshed.contains(Subsystems::NETWORK) {
+            let (rx, tx) = data.network_rates();
+            self.rx.push(rx);
+            self.tx.push(tx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let mut buffer = RingBuffer::new(3);
+        for value in 1..=5 {
+            buffer.push(value);
+        }
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(buffer.last_n(2).collect::<Vec<_>>(), vec![4, 5]);
+        assert_eq!(buffer.latest(), Some(5));
+    }
+
+    #[test]
+    fn test_history_capacity_follows_window() {
+        let history = MetricsHistory::new(Duration::from_secs(300), Duration::from_secs(1));
+        assert_eq!(history.cpu.capacity(), 300);
+    }
+
+    #[test]
+    fn test_history_skips_subsystems_not_sampled() {
+        let mut history = MetricsHistory::new(Duration::from_secs(10), Duration::from_secs(1));
+        let data = SystemData::default();
+        history.push(&data, Subsystems::SYSTEM | Subsystems::NETWORK);
+        history.push(&data, Subsystems::SYSTEM);
+        history.push(&data, Subsystems::SYSTEM);
+        history.push(&data, Subsystems::NETWORK);
+
+        assert_eq!(history.cpu.len(), 3);
+        assert_eq!(history.rx.len(), 2);
+        assert_eq!(history.tx.len(), 2);
+    }
+}
@@ -1,30 +1,53 @@
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
-use crate::{app::App, logger};
+use crate::{
+    app::{App, View, TAG_MAX_LEN},
+    components::{text_input::TextInput, LayoutMode},
+    logger,
+    types::ProcessSignal,
+};
 use color_eyre::eyre::Result;
 
+/// Lines the task detail moves per PgUp/PgDn.
+const DETAIL_SCROLL_STEP: i32 = 5;
+
 pub struct KeyHandler;
 
 impl KeyHandler {
     pub fn handle_crossterm_events(app: &mut App) -> Result<()> {
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
-                Self::on_key_event(app, key);
-            }
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => Self::on_key_event(app, key),
+            Event::Resize(width, height) => app.handle_resize(width, height),
+            _ => {}
         }
         Ok(())
     }
 
     fn on_key_event(app: &mut App, key: KeyEvent) {
-        if app.show_add_modal {
+        if app.layout_mode == LayoutMode::TooSmall {
+            // Only the size warning is drawn, so keys must not reach modals
+            // the user cannot see. The quit modal is hidden too: quit directly.
+            if matches!(
+                (key.modifiers, key.code),
+                (_, KeyCode::Char('q')) | (KeyModifiers::CONTROL, KeyCode::Char('c' | 'C'))
+            ) {
+                Self::quit(app);
+            }
+        } else if app.show_add_modal {
             // Handle input modal events
+            let save = matches!(
+                (key.modifiers, key.code),
+                (KeyModifiers::CONTROL, KeyCode::Char('s' | 'S'))
+            ) || (key.code == KeyCode::Enter
+                && app.input_current_field == crate::app::InputField::Title);
+
             match key.code {
-                KeyCode::Enter => {
+                _ if save => {
                     // Save the new TODO if title is not empty
                     if !app.input_title.is_empty() {
                         if let Err(e) = app.add_todo_to_db(
-                            &app.input_title,
-                            &app.input_message,
+                            app.input_title.value(),
+                            app.input_message.value(),
                             crate::types::Status::Todo,
                         ) {
                             let _ = logger::error(format!("Error adding TODO to DB: {}", e));
@@ -36,67 +59,77 @@ impl KeyHandler {
                         }
                     }
                     // Exit input mode
-                    app.show_add_modal = false;
-                    app.input_title.clear();
-                    app.input_message.clear();
-                    app.input_current_field = crate::app::InputField::Title;
+                    Self::close_add_modal(app);
                 }
                 KeyCode::Esc => {
                     // Cancel input
-                    app.show_add_modal = false;
-                    app.input_title.clear();
-                    app.input_message.clear();
-                    app.input_current_field = crate::app::InputField::Title;
+                    Self::close_add_modal(app);
                 }
-                KeyCode::Tab => {
+                KeyCode::Tab | KeyCode::BackTab => {
                     // Switch between input fields
                     app.input_current_field = match app.input_current_field {
-                        crate::app::InputField::Title => {
-                            // Update cursor position for the new field
-                            app.input_cursor_pos = app.input_message.len();
-                            crate::app::InputField::Message
-                        }
-                        crate::app::InputField::Message => {
-                            // Update cursor position for the new field
-                            app.input_cursor_pos = app.input_title.len();
-                            crate::app::InputField::Title
-                        }
+                        crate::app::InputField::Title => crate::app::InputField::Message,
+                        crate::app::InputField::Message => crate::app::InputField::Title,
                     };
                 }
-                KeyCode::Backspace => {
-                    // Handle backspace in the current input field
-                    match app.input_current_field {
-                        crate::app::InputField::Title => {
-                            if !app.input_title.is_empty() {
-                                app.input_title.pop();
-                                // Update cursor position
-                                app.input_cursor_pos = app.input_title.len();
-                            }
-                        }
-                        crate::app::InputField::Message => {
-                            if !app.input_message.is_empty() {
-                                app.input_message.pop();
-                                // Update cursor position
-                                app.input_cursor_pos = app.input_message.len();
-                            }
-                        }
-                    }
+                _ => {
+                    let input = match app.input_current_field {
+                        crate::app::InputField::Title => &mut app.input_title,
+                        crate::app::InputField::Message => &mut app.input_message,
+                    };
+                    input.handle_key(key);
                 }
-                KeyCode::Char(c) => match app.input_current_field {
-                    crate::app::InputField::Title => {
-                        if app.input_title.len() < 50 {
-                            app.input_title.push(c);
-                            app.input_cursor_pos = app.input_title.len();
-                        }
-                    }
-                    crate::app::InputField::Message => {
-                        if app.input_message.len() < 200 {
-                            app.input_message.push(c);
-                            app.input_cursor_pos = app.input_message.len();
-                        }
+            }
+        } else if let Some(input) = app.tag_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => {
+                    let tag = input.value().to_owned();
+                    app.tag_input = None;
+                    app.bulk_add_tag(&tag);
+                }
+                KeyCode::Esc => app.tag_input = None,
+                _ => {
+                    input.handle_key(key);
+                }
+            }
+        } else if app.process_table.filter_editing {
+            let table = &mut app.process_table;
+            match key.code {
+                KeyCode::Enter => table.filter_editing = false,
+                KeyCode::Esc => {
+                    table.filter_editing = false;
+                    table.filter.clear();
+                    table.refresh_rows();
+                }
+                KeyCode::Up | KeyCode::Down => {
+                    table.filter_editing = false;
+                    Self::on_processes_key(app, key);
+                }
+                _ => {
+                    if table.filter.handle_key(key) {
+                        table.refresh_rows();
                     }
-                },
-                _ => {}
+                }
+            }
+        } else if let Some(ids) = app.pending_delete.take() {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => app.bulk_delete(&ids),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {}
+                _ => app.pending_delete = Some(ids),
+            }
+        } else if let Some(ids) = app.pending_archive.take() {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => app.bulk_archive(&ids),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {}
+                _ => app.pending_archive = Some(ids),
+            }
+        } else if let Some(pending) = app.pending_signal.take() {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    app.send_signal(&pending)
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {}
+                _ => app.pending_signal = Some(pending),
             }
         } else if app.show_quit_modal {
             match key.code {
@@ -111,68 +144,174 @@ impl KeyHandler {
                 KeyCode::Esc | KeyCode::Char('q') => app.show_help = false,
                 _ => {}
             }
-        } else {
-            match (key.modifiers, key.code) {
-                (_, KeyCode::Esc | KeyCode::Char('q'))
-                | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => {
-                    app.show_quit_modal = true;
-                }
-                // Обработка клавиш для перемещения по списку
-                (_, KeyCode::Char('k')) => {
-                    app.list_state.previous();
-                }
-                (_, KeyCode::Char('j')) => {
-                    app.list_state.next();
+        } else if !Self::on_global_key(app, key) {
+            match app.view {
+                View::Tasks => Self::on_tasks_key(app, key),
+                View::Disks => Self::on_disks_key(app, key),
+                View::Network => Self::on_network_key(app, key),
+                View::Processes => Self::on_processes_key(app, key),
+                View::History => Self::on_history_key(app, key),
+                View::Sensors | View::Alerts => {}
+            }
+        }
+    }
+
+    /// Keys that work the same in every view. Returns `true` when consumed.
+    fn on_global_key(app: &mut App, key: KeyEvent) -> bool {
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Esc) if app.list_state.has_marks() => app.list_state.clear_marks(),
+            (_, KeyCode::Esc | KeyCode::Char('q'))
+            | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => {
+                app.show_quit_modal = true;
+            }
+            (_, KeyCode::Char('?')) => app.show_help = true,
+            (_, KeyCode::Char('s')) => app.show_sys_history = !app.show_sys_history,
+            (_, KeyCode::Char('i')) => app.show_host_details = !app.show_host_details,
+            (_, KeyCode::Char('w')) => app.show_weather = !app.show_weather,
+            (_, KeyCode::Char('W')) => app.toggle_forecast_mode(),
+            (_, KeyCode::Char('L')) => app.next_location(),
+            (_, KeyCode::Char('r')) => app.refresh_weather(),
+            (_, KeyCode::Tab) => app.set_view(app.view.next()),
+            (_, KeyCode::BackTab) => app.set_view(app.view.previous()),
+            _ => return false,
+        }
+        true
+    }
+
+    fn on_tasks_key(app: &mut App, key: KeyEvent) {
+        match (key.modifiers, key.code) {
+            // Обработка клавиш для перемещения по списку
+            (_, KeyCode::Char('k')) => {
+                app.list_state.previous();
+            }
+            (_, KeyCode::Char('j')) => {
+                app.list_state.next();
+            }
+            (_, KeyCode::Char('T')) if app.list_state.has_marks() => {
+                if let Some(item) = app.list_state.selected_item() {
+                    let mut next = item.clone();
+                    let status = next.toggle_status();
+                    app.bulk_set_status(status);
                 }
-                (_, KeyCode::Char('?')) => app.show_help = true,
-                (_, KeyCode::Char('T')) => {
-                    if let Some(i) = app.list_state.selected {
-                        let current_status = app.list_state.items[i].status;
-                        let new_status = match current_status {
-                            crate::types::Status::Todo => crate::types::Status::Active,
-                            crate::types::Status::Active => crate::types::Status::Done,
-                            crate::types::Status::Done => crate::types::Status::Cancelled,
-                            crate::types::Status::Cancelled => crate::types::Status::Todo,
-                        };
+            }
+            (_, KeyCode::Char('T')) => {
+                if let Some(i) = app.list_state.selected {
+                    let current_status = app.list_state.items[i].status;
+                    let new_status = match current_status {
+                        crate::types::Status::Todo => crate::types::Status::Active,
+                        crate::types::Status::Active => crate::types::Status::Done,
+                        crate::types::Status::Done => crate::types::Status::Cancelled,
+                        crate::types::Status::Cancelled => crate::types::Status::Todo,
+                    };
 
-                        if let Err(e) =
-                            app.update_todo_status_in_db(app.list_state.items[i].id, new_status)
-                        {
-                            let _ =
-                                logger::error(format!("Error updating TODO status in DB: {}", e));
-                        } else if let Err(e) = app.load_todos_from_db() {
-                            let _ = logger::error(format!(
-                                "Error loading todos from DB after status update: {}",
-                                e
-                            ));
-                        }
+                    if let Err(e) =
+                        app.update_todo_status_in_db(app.list_state.items[i].id, new_status)
+                    {
+                        let _ = logger::error(format!("Error updating TODO status in DB: {}", e));
+                    } else if let Err(e) = app.load_todos_from_db() {
+                        let _ = logger::error(format!(
+                            "Error loading todos from DB after status update: {}",
+                            e
+                        ));
                     }
                 }
-                (_, KeyCode::Char('A')) => {
-                    // Enter input mode for adding a new TODO
-                    app.show_add_modal = true;
-                    app.input_title.clear();
-                    app.input_message.clear();
-                    app.input_cursor_pos = 0;
-                    app.input_current_field = crate::app::InputField::Title;
+            }
+            (_, KeyCode::Char('E')) => app.request_editor(),
+            (_, KeyCode::PageDown) => app.scroll_detail(DETAIL_SCROLL_STEP),
+            (_, KeyCode::PageUp) => app.scroll_detail(-DETAIL_SCROLL_STEP),
+            (_, KeyCode::Char('A')) => {
+                // Enter input mode for adding a new TODO
+                app.show_add_modal = true;
+                app.input_title.clear();
+                app.input_message.clear();
+                app.input_current_field = crate::app::InputField::Title;
+            }
+            (_, KeyCode::Char('D')) => {
+                let ids = app.list_state.target_ids();
+                if !ids.is_empty() {
+                    app.pending_delete = Some(ids);
                 }
-                (_, KeyCode::Char('D')) => {
-                    if let Some(i) = app.list_state.selected {
-                        if let Err(e) = app.delete_todo_from_db(app.list_state.items[i].id) {
-                            let _ = logger::error(format!("Error deleting TODO from DB: {}", e));
-                        } else if let Err(e) = app.load_todos_from_db() {
-                            let _ = logger::error(format!(
-                                "Error loading todos from DB after delete: {}",
-                                e
-                            ));
-                        }
-                    }
+            }
+            (_, KeyCode::Char(' ')) => app.list_state.toggle_mark(),
+            (_, KeyCode::Char('V')) => app.list_state.toggle_visual(),
+            (_, KeyCode::Char(c @ '1'..='4')) => {
+                let status = crate::types::Status::ALL[c as usize - '1' as usize];
+                app.bulk_set_status(status);
+            }
+            (_, KeyCode::Char('#')) if !app.list_state.target_ids().is_empty() => {
+                app.tag_input = Some(TextInput::with_max_len(TAG_MAX_LEN));
+            }
+            (_, KeyCode::Char('X')) => {
+                let ids = app.list_state.target_ids();
+                if !ids.is_empty() {
+                    app.pending_archive = Some(ids);
                 }
-                _ => {}
             }
+            _ => {}
+        }
+    }
+
+    fn on_disks_key(app: &mut App, key: KeyEvent) {
+        let count = app.sys_data.disks.len();
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down if count > 0 => {
+                app.disk_selected = (app.disk_selected + 1) % count;
+            }
+            KeyCode::Char('k') | KeyCode::Up if count > 0 => {
+                app.disk_selected = (app.disk_selected + count - 1) % count;
+            }
+            _ => {}
+        }
+    }
+
+    fn on_network_key(app: &mut App, key: KeyEvent) {
+        let count = app.sys_data.networks.len();
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down if count > 0 => {
+                app.network_selected = (app.network_selected + 1) % count;
+            }
+            KeyCode::Char('k') | KeyCode::Up if count > 0 => {
+                app.network_selected = (app.network_selected + count - 1) % count;
+            }
+            _ => {}
+        }
+    }
+
+    fn on_processes_key(app: &mut App, key: KeyEvent) {
+        let table = &mut app.process_table;
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => table.next(),
+            KeyCode::Char('k') | KeyCode::Up => table.previous(),
+            KeyCode::Char('/') => table.filter_editing = true,
+            KeyCode::Char('>') => table.cycle_sort(true),
+            KeyCode::Char('<') => table.cycle_sort(false),
+            KeyCode::Char('I') => table.toggle_order(),
+            KeyCode::Char('t') => table.toggle_tree(),
+            KeyCode::Char('x') => app.request_signal(ProcessSignal::Term),
+            KeyCode::Char('X') => app.request_signal(ProcessSignal::Kill),
+            _ => {}
         }
     }
 
+    fn on_history_key(app: &mut App, key: KeyEvent) {
+        let trend = &mut app.trend;
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => trend.metric = trend.metric.next(),
+            KeyCode::Char('k') | KeyCode::Up => trend.metric = trend.metric.previous(),
+            KeyCode::Char('l') | KeyCode::Right => trend.range = trend.range.next(),
+            KeyCode::Char('h') | KeyCode::Left => trend.range = trend.range.previous(),
+            _ => return,
+        }
+        app.load_trend();
+    }
+
+    fn close_add_modal(app: &mut App) {
+        app.show_add_modal = false;
+        app.input_title.clear();
+        app.input_message.clear();
+        app.input_current_field = crate::app::InputField::Title;
+    }
+
     fn quit(app: &mut App) {
         let _ = logger::info("Quit requested by user");
         app.request_quit();
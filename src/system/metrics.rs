@@ -0,0 +1,278 @@
+use std::time::{Duration, Instant};
+
+use crate::types::SystemData;
+
+/// Seconds between raw samples written to the metrics table.
+pub const RAW_INTERVAL: Duration = Duration::from_secs(5);
+/// How often raw samples are rolled up and expired ones pruned.
+pub const ROLLUP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Stored resolutions: bucket width and how long rows of that width are kept.
+pub const RESOLUTIONS: [Resolution; 3] = [
+    Resolution {
+        bucket: 0,
+        retention: 60 * 60,
+    },
+    Resolution {
+        bucket: 60,
+        retention: 7 * 24 * 60 * 60,
+    },
+    Resolution {
+        bucket: 60 * 60,
+        retention: 90 * 24 * 60 * 60,
+    },
+];
+
+/// One retention tier of the metrics table. Raw samples have `bucket == 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolution {
+    /// Bucket width in seconds.
+    pub bucket: i64,
+    /// Age in seconds after which rows are deleted.
+    pub retention: i64,
+}
+
+impl Resolution {
+    /// The finest resolution that still covers `range` seconds back from now.
+    pub fn for_range(range: i64) -> Resolution {
+        RESOLUTIONS
+            .into_iter()
+            .find(|resolution| range <= resolution.retention)
+            .unwrap_or(RESOLUTIONS[RESOLUTIONS.len() - 1])
+    }
+}
+
+/// Values that are recorded, in the units shown by the history view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Cpu,
+    Memory,
+    Swap,
+    Disk,
+    Load,
+    NetRx,
+    NetTx,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricUnit {
+    Percent,
+    BytesPerSec,
+    Plain,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 7] = [
+        Metric::Cpu,
+        Metric::Memory,
+        Metric::Swap,
+        Metric::Disk,
+        Metric::Load,
+        Metric::NetRx,
+        Metric::NetTx,
+    ];
+
+    /// Key stored in the `metric` column.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Metric::Cpu => "cpu",
+            Metric::Memory => "memory",
+            Metric::Swap => "swap",
+            Metric::Disk => "disk",
+            Metric::Load => "load",
+            Metric::NetRx => "net_rx",
+            Metric::NetTx => "net_tx",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Metric::Cpu => "CPU usage",
+            Metric::Memory => "RAM usage",
+            Metric::Swap => "Swap usage",
+            Metric::Disk => "Fullest disk",
+            Metric::Load => "Load (1m)",
+            Metric::NetRx => "Network RX",
+            Metric::NetTx => "Network TX",
+        }
+    }
+
+    pub fn unit(self) -> MetricUnit {
+        match self {
+            Metric::Load => MetricUnit::Plain,
+            Metric::NetRx | Metric::NetTx => MetricUnit::BytesPerSec,
+            _ => MetricUnit::Percent,
+        }
+    }
+
+    pub fn value(self, data: &SystemData) -> Option<f64> {
+        match self {
+            Metric::Cpu => Some(f64::from(data.cpu.usage)),
+            Metric::Memory => Some(data.usage_memory),
+            Metric::Swap => Some(data.swap_usage()),
+            Metric::Disk => data
+                .disks
+                .iter()
+                .map(|disk| disk.usage_percent())
+                .max_by(f64::total_cmp),
+            Metric::Load => Some(data.host.load_one),
+            Metric::NetRx => Some(data.network_rates().0),
+            Metric::NetTx => Some(data.network_rates().1),
+        }
+        .filter(|value| value.is_finite())
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let i = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Time span charted by the history view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeRange {
+    Hour,
+    SixHours,
+    Day,
+    Week,
+    Month,
+    Quarter,
+}
+
+impl TimeRange {
+    pub const ALL: [TimeRange; 6] = [
+        TimeRange::Hour,
+        TimeRange::SixHours,
+        TimeRange::Day,
+        TimeRange::Week,
+        TimeRange::Month,
+        TimeRange::Quarter,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimeRange::Hour => "1h",
+            TimeRange::SixHours => "6h",
+            TimeRange::Day => "24h",
+            TimeRange::Week => "7d",
+            TimeRange::Month => "30d",
+            TimeRange::Quarter => "90d",
+        }
+    }
+
+    pub fn seconds(self) -> i64 {
+        const HOUR: i64 = 60 * 60;
+        match self {
+            TimeRange::Hour => HOUR,
+            TimeRange::SixHours => 6 * HOUR,
+            TimeRange::Day => 24 * HOUR,
+            TimeRange::Week => 7 * 24 * HOUR,
+            TimeRange::Month => 30 * 24 * HOUR,
+            TimeRange::Quarter => 90 * 24 * HOUR,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|r| *r == self).unwrap_or(0);
+        Self::ALL[(i + 1).min(Self::ALL.len() - 1)]
+    }
+
+    pub fn previous(self) -> Self {
+        let i = Self::ALL.iter().position(|r| *r == self).unwrap_or(0);
+        Self::ALL[i.saturating_sub(1)]
+    }
+}
+
+/// Decides when the next raw sample and the next rollup are due.
+#[derive(Debug)]
+pub struct MetricsRecorder {
+    last_sample: Option<Instant>,
+    last_rollup: Option<Instant>,
+}
+
+impl MetricsRecorder {
+    pub fn new() -> Self {
+        Self {
+            last_sample: None,
+            last_rollup: None,
+        }
+    }
+
+    pub fn sample_due(&mut self, now: Instant) -> bool {
+        Self::due(&mut self.last_sample, now, RAW_INTERVAL)
+    }
+
+    pub fn rollup_due(&mut self, now: Instant) -> bool {
+        Self::due(&mut self.last_rollup, now, ROLLUP_INTERVAL)
+    }
+
+    fn due(last: &mut Option<Instant>, now: Instant, interval: Duration) -> bool {
+        if last.is_some_and(|last| now.duration_since(last) < interval) {
+            return false;
+        }
+        *last = Some(now);
+        true
+    }
+}
+
+impl Default for MetricsRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State of the metrics history view.
+#[derive(Debug)]
+pub struct TrendState {
+    pub metric: Metric,
+    pub range: TimeRange,
+    /// Loaded points as (Unix timestamp, value), oldest first.
+    pub points: Vec<(f64, f64)>,
+}
+
+impl TrendState {
+    pub fn new() -> Self {
+        Self {
+            metric: Metric::Cpu,
+            range: TimeRange::Hour,
+            points: Vec::new(),
+        }
+    }
+}
+
+impl Default for TrendState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolution_for_range() {
+        assert_eq!(Resolution::for_range(TimeRange::Hour.seconds()).bucket, 0);
+        assert_eq!(Resolution::for_range(TimeRange::Day.seconds()).bucket, 60);
+        assert_eq!(Resolution::for_range(TimeRange::Week.seconds()).bucket, 60);
+        assert_eq!(
+            Resolution::for_range(TimeRange::Quarter.seconds()).bucket,
+            3600
+        );
+        assert_eq!(Resolution::for_range(i64::MAX).bucket, 3600);
+    }
+
+    #[test]
+    fn test_recorder_intervals() {
+        let mut recorder = MetricsRecorder::new();
+        let start = Instant::now();
+        assert!(recorder.sample_due(start));
+        assert!(!recorder.sample_due(start + Duration::from_secs(4)));
+        assert!(recorder.sample_due(start + RAW_INTERVAL));
+    }
+}
@@ -1,2 +1,12 @@
+pub mod alerts;
+pub mod cgroup;
+pub mod disks;
+pub mod editor;
+pub mod history;
 pub mod keys_handler;
+pub mod metrics;
+pub mod network;
+pub mod processes;
+pub mod sampler;
+pub mod sensors;
 pub mod system_info;
@@ -0,0 +1,56 @@
+use std::time::Instant;
+
+use sysinfo::Networks;
+
+use crate::types::NetworkInfo;
+
+/// Network interfaces with RX/TX throughput computed between refreshes.
+#[derive(Debug)]
+pub struct NetworkCollector {
+    networks: Networks,
+    last_refresh: Instant,
+}
+
+impl NetworkCollector {
+    pub fn new() -> Self {
+        Self {
+            networks: Networks::new_with_refreshed_list(),
+            last_refresh: Instant::now(),
+        }
+    }
+
+    pub fn collect(&mut self) -> Vec<NetworkInfo> {
+        self.networks.refresh(true);
+        let elapsed = self.last_refresh.elapsed().as_secs_f64().max(f64::EPSILON);
+        self.last_refresh = Instant::now();
+
+        let mut networks: Vec<NetworkInfo> = self
+            .networks
+            .list()
+            .iter()
+            .map(|(name, data)| NetworkInfo {
+                name: name.clone(),
+                rx_rate: data.received() as f64 / elapsed,
+                tx_rate: data.transmitted() as f64 / elapsed,
+                total_received: data.total_received(),
+                total_transmitted: data.total_transmitted(),
+                rx_errors: data.total_errors_on_received(),
+                tx_errors: data.total_errors_on_transmitted(),
+                addresses: data
+                    .ip_networks()
+                    .iter()
+                    .map(|network| format!("{}/{}", network.addr, network.prefix))
+                    .collect(),
+            })
+            .collect();
+
+        networks.sort_by(|a, b| a.name.cmp(&b.name));
+        networks
+    }
+}
+
+impl Default for NetworkCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{components::text_input::TextInput, types::ProcessInfo};
+
+/// Column the process table is ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSort {
+    Pid,
+    Name,
+    User,
+    Cpu,
+    Memory,
+    Command,
+}
+
+impl ProcessSort {
+    pub const ALL: [ProcessSort; 6] = [
+        ProcessSort::Pid,
+        ProcessSort::Name,
+        ProcessSort::User,
+        ProcessSort::Cpu,
+        ProcessSort::Memory,
+        ProcessSort::Command,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            ProcessSort::Pid => "PID",
+            ProcessSort::Name => "Name",
+            ProcessSort::User => "User",
+            ProcessSort::Cpu => "CPU%",
+            ProcessSort::Memory => "Memory",
+            ProcessSort::Command => "Command",
+        }
+    }
+
+    /// Numeric columns start out descending, text columns ascending.
+    pub fn default_descending(self) -> bool {
+        matches!(self, ProcessSort::Cpu | ProcessSort::Memory)
+    }
+
+    fn compare(self, a: &ProcessInfo, b: &ProcessInfo) -> Ordering {
+        let primary = match self {
+            ProcessSort::Pid => a.pid.cmp(&b.pid),
+            ProcessSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            ProcessSort::User => a.user.cmp(&b.user),
+            ProcessSort::Cpu => a.cpu.total_cmp(&b.cpu),
+            ProcessSort::Memory => a.memory.cmp(&b.memory),
+            ProcessSort::Command => a.command.cmp(&b.command),
+        };
+        primary.then_with(|| a.pid.cmp(&b.pid))
+    }
+}
+
+/// A visible line of the table: an index into the process list and its depth
+/// in tree mode (always 0 in flat mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessRow {
+    pub index: usize,
+    pub depth: usize,
+}
+
+/// Filter and order `processes` for display. In tree mode children follow
+/// their parent, siblings are ordered by `sort`, and ancestors of filter
+/// matches are kept so the tree stays connected.
+pub fn arrange(
+    processes: &[ProcessInfo],
+    filter: &str,
+    sort: ProcessSort,
+    descending: bool,
+    tree: bool,
+) -> Vec<ProcessRow> {
+    let compare = |a: &usize, b: &usize| {
+        let ordering = sort.compare(&processes[*a], &processes[*b]);
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+
+    let query = filter.trim().to_lowercase();
+    let matches = |process: &ProcessInfo| {
+        query.is_empty()
+            || process.pid.to_string().contains(&query)
+            || process.name.to_lowercase().contains(&query)
+            || process.user.to_lowercase().contains(&query)
+            || process.command.to_lowercase().contains(&query)
+    };
+
+    if !tree {
+        let mut indices: Vec<usize> = (0..processes.len())
+            .filter(|&i| matches(&processes[i]))
+            .collect();
+        indices.sort_by(compare);
+        return indices
+            .into_iter()
+            .map(|index| ProcessRow { index, depth: 0 })
+            .collect();
+    }
+
+    let by_pid: HashMap<u32, usize> = processes
+        .iter()
+        .enumerate()
+        .map(|(i, process)| (process.pid, i))
+        .collect();
+    let parent_of = |i: usize| {
+        processes[i]
+            .parent
+            .and_then(|pid| by_pid.get(&pid).copied())
+            .filter(|&parent| parent != i)
+    };
+
+    let mut visible = HashSet::new();
+    for i in (0..processes.len()).filter(|&i| matches(&processes[i])) {
+        let mut current = Some(i);
+        while let Some(node) = current {
+            if !visible.insert(node) {
+                break;
+            }
+            current = parent_of(node);
+        }
+    }
+
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut roots = Vec::new();
+    for &i in &visible {
+        match parent_of(i) {
+            Some(parent) => children.entry(parent).or_default().push(i),
+            None => roots.push(i),
+        }
+    }
+    roots.sort_by(compare);
+    for siblings in children.values_mut() {
+        siblings.sort_by(compare);
+    }
+
+    let mut rows = Vec::with_capacity(visible.len());
+    let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|i| (i, 0)).collect();
+    let mut seen = HashSet::new();
+    while let Some((index, depth)) = stack.pop() {
+        if !seen.insert(index) {
+            continue;
+        }
+        rows.push(ProcessRow { index, depth });
+        if let Some(kids) = children.get(&index) {
+            stack.extend(kids.iter().rev().map(|&kid| (kid, depth + 1)));
+        }
+    }
+    rows
+}
+
+/// State of the process view: the latest snapshot and how it is presented.
+#[derive(Debug)]
+pub struct ProcessTable {
+    pub processes: Vec<ProcessInfo>,
+    pub rows: Vec<ProcessRow>,
+    pub sort: ProcessSort,
+    pub descending: bool,
+    pub tree: bool,
+    pub filter: TextInput,
+    /// Whether keys go to the filter input.
+    pub filter_editing: bool,
+    pub selected: usize,
+}
+
+impl ProcessTable {
+    pub fn new() -> Self {
+        Self {
+            processes: Vec::new(),
+            rows: Vec::new(),
+            sort: ProcessSort::Cpu,
+            descending: true,
+            tree: false,
+            filter: TextInput::new(),
+            filter_editing: false,
+            selected: 0,
+        }
+    }
+
+    /// Replace the snapshot, keeping the selection on the same PID.
+    pub fn set_processes(&mut self, processes: Vec<ProcessInfo>) {
+        let pid = self.selected_process().map(|process| process.pid);
+        self.processes = processes;
+        self.rearrange(pid);
+    }
+
+    /// Recompute the visible rows after the filter, order or mode changed.
+    pub fn refresh_rows(&mut self) {
+        let pid = self.selected_process().map(|process| process.pid);
+        self.rearrange(pid);
+    }
+
+    fn rearrange(&mut self, pid: Option<u32>) {
+        self.rows = arrange(
+            &self.processes,
+            self.filter.value(),
+            self.sort,
+            self.descending,
+            self.tree,
+        );
+        self.selected = pid
+            .and_then(|pid| {
+                self.rows
+                    .iter()
+                    .position(|row| self.processes[row.index].pid == pid)
+            })
+            .unwrap_or(self.selected)
+            .min(self.rows.len().saturating_sub(1));
+    }
+
+    pub fn selected_process(&self) -> Option<&ProcessInfo> {
+        self.rows
+            .get(self.selected)
+            .map(|row| &self.processes[row.index])
+    }
+
+    pub fn next(&mut self) {
+        if !self.rows.is_empty() {
+            self.selected = (self.selected + 1) % self.rows.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.rows.is_empty() {
+            self.selected = (self.selected + self.rows.len() - 1) % self.rows.len();
+        }
+    }
+
+    /// Move to the next (or previous) sort column.
+    pub fn cycle_sort(&mut self, forward: bool) {
+        let i = ProcessSort::ALL
+            .iter()
+            .position(|sort| *sort == self.sort)
+            .unwrap_or(0);
+        let len = ProcessSort::ALL.len();
+        let i = if forward { i + 1 } else { i + len - 1 } % len;
+        self.sort = ProcessSort::ALL[i];
+        self.descending = self.sort.default_descending();
+        self.refresh_rows();
+    }
+
+    pub fn toggle_order(&mut self) {
+        self.descending = !self.descending;
+        self.refresh_rows();
+    }
+
+    pub fn toggle_tree(&mut self) {
+        self.tree = !self.tree;
+        self.refresh_rows();
+    }
+}
+
+impl Default for ProcessTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, parent: Option<u32>, name: &str, cpu: f32) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            parent,
+            name: name.to_string(),
+            user: "root".to_string(),
+            cpu,
+            memory: u64::from(pid) * 1024,
+            command: format!("/usr/bin/{name}"),
+            start_time: 0,
+        }
+    }
+
+    fn sample() -> Vec<ProcessInfo> {
+        vec![
+            process(1, None, "init", 0.5),
+            process(20, Some(1), "sshd", 1.0),
+            process(31, Some(20), "bash", 3.0),
+            process(40, Some(1), "cron", 0.0),
+            process(52, Some(31), "vim", 12.0),
+        ]
+    }
+
+    fn pids(processes: &[ProcessInfo], rows: &[ProcessRow]) -> Vec<(u32, usize)> {
+        rows.iter()
+            .map(|row| (processes[row.index].pid, row.depth))
+            .collect()
+    }
+
+    #[test]
+    fn test_flat_sort_and_order() {
+        let processes = sample();
+        let rows = arrange(&processes, "", ProcessSort::Cpu, true, false);
+        let order: Vec<u32> = pids(&processes, &rows).iter().map(|p| p.0).collect();
+        assert_eq!(order, vec![52, 31, 20, 1, 40]);
+
+        let rows = arrange(&processes, "", ProcessSort::Name, false, false);
+        let order: Vec<u32> = pids(&processes, &rows).iter().map(|p| p.0).collect();
+        assert_eq!(order, vec![31, 40, 1, 20, 52]);
+    }
+
+    #[test]
+    fn test_filter_matches_name_command_and_pid() {
+        let processes = sample();
+        let rows = arrange(&processes, "SSH", ProcessSort::Pid, false, false);
+        assert_eq!(pids(&processes, &rows), vec![(20, 0)]);
+
+        let rows = arrange(&processes, "52", ProcessSort::Pid, false, false);
+        assert_eq!(pids(&processes, &rows), vec![(52, 0)]);
+    }
+
+    #[test]
+    fn test_tree_orders_children_under_parents() {
+        let processes = sample();
+        let rows = arrange(&processes, "", ProcessSort::Pid, false, true);
+        assert_eq!(
+            pids(&processes, &rows),
+            vec![(1, 0), (20, 1), (31, 2), (52, 3), (40, 1)]
+        );
+    }
+
+    #[test]
+    fn test_tree_filter_keeps_ancestors() {
+        let processes = sample();
+        let rows = arrange(&processes, "vim", ProcessSort::Pid, false, true);
+        assert_eq!(
+            pids(&processes, &rows),
+            vec![(1, 0), (20, 1), (31, 2), (52, 3)]
+        );
+    }
+
+    #[test]
+    fn test_selection_follows_pid_across_refresh() {
+        let mut table = ProcessTable::new();
+        table.set_processes(sample());
+        table.next();
+        assert_eq!(table.selected_process().map(|p| p.pid), Some(31));
+
+        let mut changed = sample();
+        changed[2].cpu = 50.0;
+        table.set_processes(changed);
+        assert_eq!(table.selected, 0);
+        assert_eq!(table.selected_process().map(|p| p.pid), Some(31));
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    ops::BitOr,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use crate::{
+    logger,
+    system::system_info::System,
+    types::{ProcessInfo, SystemData},
+};
+
+/// Set of independently refreshed parts of the system snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Subsystems(u8);
+
+impl Subsystems {
+    pub const NONE: Self = Self(0);
+    /// CPU, memory, swap, load and cgroup limits.
+    pub const SYSTEM: Self = Self(1);
+    pub const DISKS: Self = Self(1 << 1);
+    pub const NETWORK: Self = Self(1 << 2);
+    pub const SENSORS: Self = Self(1 << 3);
+    pub const PROCESSES: Self = Self(1 << 4);
+    /// Everything but processes, which are only scanned on demand.
+    pub const ALL: Self = Self(0b1111);
+
+    const EACH: [Self; 5] = [
+        Self::SYSTEM,
+        Self::DISKS,
+        Self::NETWORK,
+        Self::SENSORS,
+        Self::PROCESSES,
+    ];
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    fn index(self) -> usize {
+        self.0.trailing_zeros() as usize
+    }
+}
+
+impl BitOr for Subsystems {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// How often each subsystem is refreshed while it is needed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RefreshRates {
+    pub system: Duration,
+    pub disks: Duration,
+    pub network: Duration,
+    pub sensors: Duration,
+    pub processes: Duration,
+}
+
+impl RefreshRates {
+    fn of(&self, subsystem: Subsystems) -> Duration {
+        match subsystem {
+            Subsystems::DISKS => self.disks,
+            Subsystems::NETWORK => self.network,
+            Subsystems::SENSORS => self.sensors,
+            Subsystems::PROCESSES => self.processes,
+            _ => self.system,
+        }
+    }
+}
+
+impl Default for RefreshRates {
+    fn default() -> Self {
+        Self {
+            system: Duration::from_secs(1),
+            disks: Duration::from_secs(2),
+            network: Duration::from_secs(1),
+            sensors: Duration::from_secs(2),
+            processes: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Sent by the UI to steer the sampler.
+#[derive(Debug, Clone, Copy)]
+pub enum SamplerCommand {
+    /// Subsystems currently on screen, replacing the previous set.
+    Display(Subsystems),
+    /// Refresh these subsystems right away, e.g. after killing a process.
+    Refresh(Subsystems),
+}
+
+/// Result of one sampling round.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub data: SystemData,
+    /// Subsystems refreshed in this round; the rest of `data` is unchanged.
+    pub refreshed: Subsystems,
+    /// Present when processes were refreshed.
+    pub processes: Option<Vec<ProcessInfo>>,
+}
+
+/// Collects system data off the UI thread. Subsystems are refreshed at their
+/// own rate, and only while displayed or needed in the `background` (alerts,
+/// metrics recording, the exporter).
+#[derive(Debug)]
+pub struct Sampler {
+    system: System,
+    rates: RefreshRates,
+    background: Subsystems,
+    displayed: Subsystems,
+    forced: Subsystems,
+    last_refresh: [Option<Instant>; 5],
+    data: SystemData,
+}
+
+impl Sampler {
+    pub fn new(rates: RefreshRates, background: Subsystems) -> Self {
+        Self {
+            system: System::new(),
+            rates,
+            background: background | Subsystems::SYSTEM,
+            displayed: Subsystems::NONE,
+            forced: Subsystems::NONE,
+            last_refresh: [None; 5],
+            data: SystemData::default(),
+        }
+    }
+
+    /// Sample until `publish` reports that nobody listens anymore or the
+    /// command channel closes. sysinfo reads block, so each round runs on
+    /// the blocking thread pool.
+    pub async fn run(
+        mut self,
+        mut commands: UnboundedReceiver<SamplerCommand>,
+        mut publish: impl FnMut(Sample) -> bool,
+    ) {
+        loop {
+            let due = self.due(Instant::now());
+            if !due.is_empty() {
+                let round = tokio::task::spawn_blocking(move || {
+                    let sample = self.sample(due);
+                    (self, sample)
+                })
+                .await;
+                let sample;
+                (self, sample) = match round {
+                    Ok(round) => round,
+                    Err(e) => {
+                        let _ = logger::error(format!("System sampler stopped: {e}"));
+                        return;
+                    }
+                };
+                if !publish(sample) {
+                    return;
+                }
+            }
+
+            let next = self.next_due(Instant::now());
+            tokio::select! {
+                () = tokio::time::sleep_until(next.into()) => {}
+                command = commands.recv() => match command {
+                    Some(SamplerCommand::Display(subsystems)) => self.displayed = subsystems,
+                    Some(SamplerCommand::Refresh(subsystems)) => {
+                        self.forced = self.forced | subsystems;
+                    }
+                    None => return,
+                },
+            }
+        }
+    }
+
+    fn needed(&self) -> Subsystems {
+        self.background | self.displayed
+    }
+
+    /// Subsystems whose refresh interval has passed, plus forced ones.
+    fn due(&self, now: Instant) -> Subsystems {
+        let needed = self.needed();
+        Subsystems::EACH
+            .into_iter()
+            .filter(|subsystem| needed.contains(*subsystem))
+            .filter(|subsystem| {
+                self.forced.contains(*subsystem)
+                    || self.last_refresh[subsystem.index()]
+                        .is_none_or(|last| now.duration_since(last) >= self.rates.of(*subsystem))
+            })
+            .fold(Subsystems::NONE, BitOr::bitor)
+    }
+
+    /// When the next needed subsystem becomes due.
+    fn next_due(&self, now: Instant) -> Instant {
+        let needed = self.needed();
+        Subsystems::EACH
+            .into_iter()
+            .filter(|subsystem| needed.contains(*subsystem))
+            .map(|subsystem| {
+                self.last_refresh[subsystem.index()]
+                    .map_or(now, |last| last + self.rates.of(subsystem))
+            })
+            .min()
+            .unwrap_or(now + self.rates.system)
+    }
+
+    fn sample(&mut self, subsystems: Subsystems) -> Sample {
+        self.system.sample(&mut self.data, subsystems);
+        let processes = subsystems
+            .contains(Subsystems::PROCESSES)
+            .then(|| self.system.get_processes());
+
+        let now = Instant::now();
+        for subsystem in Subsystems::EACH {
+            if subsystems.contains(subsystem) {
+                self.last_refresh[subsystem.index()] = Some(now);
+            }
+        }
+        self.forced = Subsystems::NONE;
+
+        Sample {
+            data: self.data.clone(),
+            refreshed: subsystems,
+            processes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sampler(background: Subsystems) -> Sampler {
+        let rates = RefreshRates {
+            system: Duration::from_secs(1),
+            disks: Duration::from_secs(5),
+            ..RefreshRates::default()
+        };
+        Sampler::new(rates, background)
+    }
+
+    #[test]
+    fn test_subsystem_set() {
+        let set = Subsystems::SYSTEM | Subsystems::DISKS;
+        assert!(set.contains(Subsystems::DISKS));
+        assert!(!set.contains(Subsystems::NETWORK));
+        assert!(Subsystems::ALL.contains(Subsystems::SENSORS));
+        assert!(!Subsystems::ALL.contains(Subsystems::PROCESSES));
+    }
+
+    #[test]
+    fn test_only_needed_subsystems_are_due() {
+        let mut sampler = sampler(Subsystems::DISKS);
+        let now = Instant::now();
+        assert_eq!(sampler.due(now), Subsystems::SYSTEM | Subsystems::DISKS);
+
+        sampler.displayed = Subsystems::PROCESSES;
+        assert_eq!(
+            sampler.due(now),
+            Subsystems::SYSTEM | Subsystems::DISKS | Subsystems::PROCESSES
+        );
+    }
+
+    #[test]
+    fn test_refresh_rates_per_subsystem() {
+        let mut sampler = sampler(Subsystems::DISKS);
+        let start = Instant::now();
+        sampler.last_refresh[Subsystems::SYSTEM.index()] = Some(start);
+        sampler.last_refresh[Subsystems::DISKS.index()] = Some(start);
+
+        assert_eq!(sampler.due(start), Subsystems::NONE);
+        assert_eq!(sampler.next_due(start), start + Duration::from_secs(1));
+        assert_eq!(
+            sampler.due(start + Duration::from_secs(2)),
+            Subsystems::SYSTEM
+        );
+        assert_eq!(
+            sampler.due(start + Duration::from_secs(5)),
+            Subsystems::SYSTEM | Subsystems::DISKS
+        );
+
+        sampler.forced = Subsystems::DISKS;
+        assert_eq!(sampler.due(start), Subsystems::DISKS);
+    }
+
+    #[tokio::test]
+    async fn test_run_publishes_samples_until_dropped() {
+        let (commands_tx, commands_rx) = tokio::sync::mpsc::unbounded_channel();
+        let rates = RefreshRates {
+            system: Duration::from_millis(100),
+            ..RefreshRates::default()
+        };
+        let mut samples = Vec::new();
+        commands_tx
+            .send(SamplerCommand::Refresh(Subsystems::PROCESSES))
+            .unwrap();
+        commands_tx
+            .send(SamplerCommand::Display(Subsystems::PROCESSES))
+            .unwrap();
+
+        Sampler::new(rates, Subsystems::NONE)
+            .run(commands_rx, |sample| {
+                samples.push(sample);
+                samples.len() < 3
+            })
+            .await;
+
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0].refreshed, Subsystems::SYSTEM);
+        assert!(samples[0].data.cpu.len > 0);
+        assert!(samples
+            .iter()
+            .any(|sample| sample.processes.as_ref().is_some_and(|p| !p.is_empty())));
+    }
+}
@@ -0,0 +1,42 @@
+use sysinfo::Components;
+
+use crate::types::SensorInfo;
+
+/// Temperature sensors exposed by the hardware. Empty on most VMs.
+#[derive(Debug)]
+pub struct SensorCollector {
+    components: Components,
+}
+
+impl SensorCollector {
+    pub fn new() -> Self {
+        Self {
+            components: Components::new_with_refreshed_list(),
+        }
+    }
+
+    pub fn collect(&mut self) -> Vec<SensorInfo> {
+        self.components.refresh(true);
+
+        let mut sensors: Vec<SensorInfo> = self
+            .components
+            .list()
+            .iter()
+            .map(|component| SensorInfo {
+                label: component.label().to_string(),
+                temperature: component.temperature().filter(|t| t.is_finite()),
+                max: component.max().filter(|t| t.is_finite()),
+                critical: component.critical().filter(|t| t.is_finite() && *t > 0.0),
+            })
+            .collect();
+
+        sensors.sort_by(|a, b| a.label.cmp(&b.label));
+        sensors
+    }
+}
+
+impl Default for SensorCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,30 +1,130 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use sysinfo::{
+    CpuRefreshKind, MemoryRefreshKind, Pid, ProcessRefreshKind, ProcessesToUpdate, RefreshKind,
+    Signal, UpdateKind, Users,
+};
+
 use crate::{
     logger::{self, log_once},
-    types::{CpuInfo, SystemData},
+    system::{
+        cgroup::{self, Cgroup, CgroupLimits},
+        disks::DiskCollector,
+        network::NetworkCollector,
+        sampler::Subsystems,
+        sensors::SensorCollector,
+    },
+    types::{
+        ContainerInfo, CpuInfo, HostInfo, PendingSignal, ProcessInfo, ProcessSignal, SystemData,
+    },
 };
 
 const BYTES_TO_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+/// Limits rarely change, so they are re-read at this rate rather than on
+/// every sample.
+const CGROUP_LIMITS_REFRESH: Duration = Duration::from_secs(30);
 
 #[derive(Debug)]
 pub struct System {
     system: sysinfo::System,
+    disks: DiskCollector,
+    networks: NetworkCollector,
+    sensors: SensorCollector,
+    /// Loaded with the first process snapshot.
+    users: Users,
+    /// Host identity does not change while running, so it is read once.
+    identity: HostInfo,
+    /// Located once; `None` outside a cgroup hierarchy.
+    cgroup: Option<Cgroup>,
+    /// Last read limits and when they were read.
+    cgroup_limits: Option<(CgroupLimits, Instant)>,
+    /// Previous reading of the cgroup's CPU time counter.
+    cgroup_cpu: Option<(u64, Instant)>,
+    runtime: Option<&'static str>,
 }
 
 impl System {
     pub fn new() -> Self {
-        let mut system = sysinfo::System::new_all();
-
-        system.refresh_memory();
-        Self { system }
+        // Processes are scanned only once the process view asks for them.
+        let system = sysinfo::System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_cpu(CpuRefreshKind::everything())
+                .with_memory(MemoryRefreshKind::everything()),
+        );
+        let self_cgroup = fs::read_to_string("/proc/self/cgroup").unwrap_or_default();
+        Self {
+            system,
+            disks: DiskCollector::new(),
+            networks: NetworkCollector::new(),
+            sensors: SensorCollector::new(),
+            users: Users::new(),
+            identity: host_identity(),
+            cgroup: cgroup::locate(Path::new(CGROUP_ROOT), &self_cgroup),
+            cgroup_limits: None,
+            cgroup_cpu: None,
+            runtime: cgroup::container_runtime(Path::new("/"), &self_cgroup),
+        }
     }
 
+    /// Sample every subsystem except processes.
     pub fn get_info(&mut self) -> SystemData {
+        let mut data = SystemData::default();
+        self.sample(&mut data, Subsystems::ALL);
+        data
+    }
+
+    /// Refresh the given subsystems and store them into `data`, leaving the
+    /// other fields as they were.
+    pub fn sample(&mut self, data: &mut SystemData, subsystems: Subsystems) {
+        if subsystems.contains(Subsystems::SYSTEM) {
+            self.sample_usage(data);
+        }
+        if subsystems.contains(Subsystems::DISKS) {
+            data.disks = self.disks.collect();
+        }
+        if subsystems.contains(Subsystems::NETWORK) {
+            data.networks = self.networks.collect();
+        }
+        if subsystems.contains(Subsystems::SENSORS) {
+            data.sensors = self.sensors.collect();
+        }
+    }
+
+    /// CPU, memory, swap, load and cgroup limits.
+    fn sample_usage(&mut self, data: &mut SystemData) {
         log_once("get_info init", |msg| logger::info(msg));
         self.system.refresh_memory();
+        self.system.refresh_cpu_usage();
 
-        let total_memory = self.system.total_memory();
-        let used_memory = self.system.used_memory();
-        let available_memory = self.system.available_memory();
+        let mut total_memory = self.system.total_memory();
+        let mut used_memory = self.system.used_memory();
+        let mut available_memory = self.system.available_memory();
+        let cpu = self.get_cpu_data().unwrap_or_default();
+
+        let limits = self
+            .cgroup_limits()
+            .filter(|limits| limits.is_limited() || self.runtime.is_some());
+        let quota_usage = self.cgroup_quota_usage(limits.as_ref().and_then(|l| l.cpu_quota));
+        let container = limits.map(|limits| {
+            // A limit above the host's memory never kicks in.
+            if let Some(limit) = limits.memory_limit.filter(|limit| *limit < total_memory) {
+                total_memory = limit;
+                used_memory = limits.memory_used.unwrap_or(used_memory).min(limit);
+                available_memory = limit - used_memory;
+            }
+            ContainerInfo {
+                runtime: self.runtime.map(str::to_string),
+                cgroup_version: limits.version,
+                memory_limit: limits.memory_limit.map(bytes_to_gb),
+                cpu_quota: limits.cpu_quota,
+                cpu_usage: quota_usage,
+            }
+        });
 
         let usage_percent = if total_memory > 0 {
             (used_memory as f64 / total_memory as f64) * 100.0
@@ -32,14 +132,153 @@ impl System {
             0.0
         };
 
-        let cpu = self.get_cpu_data().unwrap_or_default();
+        data.total_memory = bytes_to_gb(total_memory);
+        data.used_memory = bytes_to_gb(used_memory);
+        data.available_memory = bytes_to_gb(available_memory);
+        data.usage_memory = usage_percent;
+        data.swap_total = bytes_to_gb(self.system.total_swap());
+        data.swap_used = bytes_to_gb(self.system.used_swap());
+        data.cpu = cpu;
+        data.host = self.get_host_data();
+        data.container = container;
+    }
+
+    /// Limits of our cgroup, re-read every [`CGROUP_LIMITS_REFRESH`]; memory
+    /// usage is read on every call.
+    fn cgroup_limits(&mut self) -> Option<CgroupLimits> {
+        let cgroup = self.cgroup.as_ref()?;
+        match &mut self.cgroup_limits {
+            Some((limits, read_at)) if read_at.elapsed() < CGROUP_LIMITS_REFRESH => {
+                limits.memory_used = cgroup.memory_used();
+                Some(limits.clone())
+            }
+            cached => {
+                let limits = cgroup.limits();
+                *cached = Some((limits.clone(), Instant::now()));
+                Some(limits)
+            }
+        }
+    }
+
+    /// Share of the CPU quota the cgroup used since the previous sample,
+    /// from its own CPU time counter; host-wide usage says nothing about it.
+    fn cgroup_quota_usage(&mut self, quota: Option<f64>) -> Option<f32> {
+        let used = self.cgroup.as_ref()?.cpu_usage_usec()?;
+        let now = Instant::now();
+        let previous = self.cgroup_cpu.replace((used, now));
+        let (previous_used, previous_at) = previous?;
+        cgroup::quota_usage(
+            used.saturating_sub(previous_used),
+            now - previous_at,
+            quota?,
+        )
+    }
+
+    fn get_host_data(&self) -> HostInfo {
+        let load = sysinfo::System::load_average();
+        HostInfo {
+            uptime: sysinfo::System::uptime(),
+            boot_time: sysinfo::System::boot_time(),
+            load_one: load.one,
+            load_five: load.five,
+            load_fifteen: load.fifteen,
+            ..self.identity.clone()
+        }
+    }
 
-        SystemData {
-            total_memory: bytes_to_gb(total_memory),
-            used_memory: bytes_to_gb(used_memory),
-            available_memory: bytes_to_gb(available_memory),
-            usage_memory: usage_percent,
-            cpu,
+    /// Snapshot of running processes. CPU usage is relative to the previous
+    /// call, so the first snapshot reports 0% for everything.
+    pub fn get_processes(&mut self) -> Vec<ProcessInfo> {
+        if self.users.list().is_empty() {
+            self.users.refresh();
+        }
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_user(UpdateKind::OnlyIfNotSet)
+                .with_cmd(UpdateKind::OnlyIfNotSet),
+        );
+
+        self.system
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind().is_none())
+            .map(|process| {
+                let name = process.name().to_string_lossy().into_owned();
+                let command = process
+                    .cmd()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let user = process
+                    .user_id()
+                    .map(|uid| {
+                        self.users
+                            .get_user_by_id(uid)
+                            .map_or_else(|| uid.to_string(), |user| user.name().to_string())
+                    })
+                    .unwrap_or_default();
+
+                ProcessInfo {
+                    pid: process.pid().as_u32(),
+                    parent: process.parent().map(Pid::as_u32),
+                    command: if command.is_empty() {
+                        format!("[{name}]")
+                    } else {
+                        command
+                    },
+                    name,
+                    user,
+                    cpu: process.cpu_usage(),
+                    memory: process.memory(),
+                    start_time: process.start_time(),
+                }
+            })
+            .collect()
+    }
+
+    /// Send the confirmed signal. Only that process is looked up, so this is
+    /// cheap to call from the UI thread. Nothing is sent when the PID now
+    /// belongs to another process than the one that was confirmed.
+    pub fn send_signal(pending: &PendingSignal) -> Result<(), String> {
+        let signal = pending.signal;
+        let pid = Pid::from_u32(pending.pid);
+        let mut system = sysinfo::System::new();
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing(),
+        );
+        let process = system
+            .process(pid)
+            .ok_or_else(|| format!("process {pid} no longer exists"))?;
+        if process.start_time() != pending.start_time
+            || process.name().to_string_lossy() != pending.name
+        {
+            return Err(format!(
+                "process {} ({pid}) has exited, the PID now belongs to another process",
+                pending.name
+            ));
+        }
+        let signal_kind = match signal {
+            ProcessSignal::Term => Signal::Term,
+            ProcessSignal::Kill => Signal::Kill,
+        };
+
+        match process.kill_with(signal_kind) {
+            Some(true) => Ok(()),
+            Some(false) => Err(format!(
+                "failed to send {} to {pid} (permission denied?)",
+                signal.as_str()
+            )),
+            None => Err(format!(
+                "{} is not supported on this platform",
+                signal.as_str()
+            )),
         }
     }
 
@@ -51,6 +290,13 @@ impl System {
                 len: self.system.cpus().len(),
                 frequency: cpu.frequency(),
                 brand: cpu.brand().to_string(),
+                usage: self.system.global_cpu_usage(),
+                cores: self
+                    .system
+                    .cpus()
+                    .iter()
+                    .map(|cpu| cpu.cpu_usage())
+                    .collect(),
             })
         } else {
             Err("Cpu information not found".to_string())
@@ -64,6 +310,18 @@ impl Default for System {
     }
 }
 
+fn host_identity() -> HostInfo {
+    let unknown = || "unknown".to_string();
+    HostInfo {
+        hostname: sysinfo::System::host_name().unwrap_or_else(unknown),
+        os_name: sysinfo::System::long_os_version()
+            .or_else(sysinfo::System::name)
+            .unwrap_or_else(unknown),
+        kernel: sysinfo::System::kernel_version().unwrap_or_else(unknown),
+        ..HostInfo::default()
+    }
+}
+
 fn bytes_to_gb(bytes: u64) -> f64 {
     bytes as f64 / BYTES_TO_GB
 }
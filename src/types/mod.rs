@@ -1,37 +1,308 @@
-use chrono::{DateTime, Local};
+use std::{
+    collections::BTreeSet,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDate};
 use ratatui::style::Color;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SystemData {
     pub total_memory: f64,
     pub used_memory: f64,
     pub available_memory: f64,
     pub usage_memory: f64,
+    pub swap_total: f64,
+    pub swap_used: f64,
     pub cpu: CpuInfo,
+    pub disks: Vec<DiskInfo>,
+    pub networks: Vec<NetworkInfo>,
+    pub host: HostInfo,
+    pub sensors: Vec<SensorInfo>,
+    /// Set when running under a cgroup with memory or CPU limits; memory
+    /// figures above are then relative to the cgroup limit.
+    pub container: Option<ContainerInfo>,
+}
+
+impl SystemData {
+    /// Swap usage in percent, 0 when there is no swap.
+    pub fn swap_usage(&self) -> f64 {
+        if self.swap_total > 0.0 {
+            self.swap_used / self.swap_total * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Combined receive and transmit rate of all interfaces, in bytes/s.
+    pub fn network_rates(&self) -> (f64, f64) {
+        self.networks.iter().fold((0.0, 0.0), |(rx, tx), net| {
+            (rx + net.rx_rate, tx + net.tx_rate)
+        })
+    }
+}
+
+/// Critical temperature assumed for sensors that do not report one, in °C.
+pub const DEFAULT_CRITICAL_TEMP: f32 = 100.0;
+
+/// A temperature sensor; all readings are in °C.
+#[derive(Debug, Clone, Default)]
+pub struct SensorInfo {
+    pub label: String,
+    pub temperature: Option<f32>,
+    /// Highest reading seen since the sensor was first read.
+    pub max: Option<f32>,
+    pub critical: Option<f32>,
+}
+
+impl SensorInfo {
+    /// Current reading as a fraction of the critical temperature.
+    pub fn critical_ratio(&self) -> Option<f32> {
+        let critical = self.critical.unwrap_or(DEFAULT_CRITICAL_TEMP);
+        self.temperature.map(|temp| temp / critical)
+    }
+}
+
+/// Identity and uptime of the machine plus the 1/5/15-minute load averages.
+#[derive(Debug, Clone, Default)]
+pub struct HostInfo {
+    pub hostname: String,
+    pub os_name: String,
+    pub kernel: String,
+    /// Seconds since boot.
+    pub uptime: u64,
+    /// Boot time as a Unix timestamp.
+    pub boot_time: u64,
+    pub load_one: f64,
+    pub load_five: f64,
+    pub load_fifteen: f64,
+}
+
+impl HostInfo {
+    pub fn boot_time_local(&self) -> Option<DateTime<Local>> {
+        DateTime::from_timestamp(i64::try_from(self.boot_time).ok()?, 0)
+            .map(|time| time.with_timezone(&Local))
+    }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+/// Limits of the cgroup tuitask runs in.
+#[derive(Debug, Clone, Default)]
+pub struct ContainerInfo {
+    /// Container runtime, `None` for a plain cgroup such as a systemd slice.
+    pub runtime: Option<String>,
+    pub cgroup_version: u8,
+    /// Memory limit in GiB.
+    pub memory_limit: Option<f64>,
+    /// CPU quota in cores.
+    pub cpu_quota: Option<f64>,
+    /// CPU usage relative to the quota, in percent.
+    pub cpu_usage: Option<f32>,
+}
+
+impl ContainerInfo {
+    pub fn label(&self) -> String {
+        let mut parts = vec![self.runtime.clone().unwrap_or_else(|| "cgroup".to_string())];
+        parts.push(format!("v{}", self.cgroup_version));
+        if let Some(quota) = self.cpu_quota {
+            parts.push(format!("{quota:.1} CPUs"));
+        }
+        if let Some(limit) = self.memory_limit {
+            parts.push(crate::units::format_gib(limit, 1));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// Usage above which a volume is highlighted as nearly full, in percent.
+pub const DISK_WARN_PERCENT: f64 = 90.0;
+
+#[derive(Debug, Clone, Default)]
+pub struct DiskInfo {
+    pub name: String,
+    pub mount_point: String,
+    pub file_system: String,
+    pub total: u64,
+    pub available: u64,
+    pub used: u64,
+    /// Bytes per second read since the previous sample.
+    pub read_rate: f64,
+    /// Bytes per second written since the previous sample.
+    pub write_rate: f64,
+    pub is_removable: bool,
+}
+
+impl DiskInfo {
+    pub fn usage_percent(&self) -> f64 {
+        if self.total > 0 {
+            self.used as f64 / self.total as f64 * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_nearly_full(&self) -> bool {
+        self.usage_percent() >= DISK_WARN_PERCENT
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NetworkInfo {
+    pub name: String,
+    /// Bytes per second received since the previous sample.
+    pub rx_rate: f64,
+    /// Bytes per second transmitted since the previous sample.
+    pub tx_rate: f64,
+    pub total_received: u64,
+    pub total_transmitted: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    /// Local addresses in CIDR notation.
+    pub addresses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub parent: Option<u32>,
+    pub name: String,
+    pub user: String,
+    /// Percent of one core; can exceed 100 for multi-threaded processes.
+    pub cpu: f32,
+    /// Resident memory in bytes.
+    pub memory: u64,
+    pub command: String,
+    /// Seconds since the epoch; tells a process apart from a later one that
+    /// reuses its PID.
+    pub start_time: u64,
+}
+
+/// Signals that can be sent from the process view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSignal {
+    Term,
+    Kill,
+}
+
+impl ProcessSignal {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProcessSignal::Term => "SIGTERM",
+            ProcessSignal::Kill => "SIGKILL",
+        }
+    }
+}
+
+/// A signal waiting for the user's confirmation.
+#[derive(Debug, Clone)]
+pub struct PendingSignal {
+    pub pid: u32,
+    pub name: String,
+    /// Start time of the process when it was chosen, see
+    /// [`ProcessInfo::start_time`].
+    pub start_time: u64,
+    pub signal: ProcessSignal,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct GeoData {
     pub latitude: f64,
     pub longitude: f64,
+    #[serde(default)]
+    pub city: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct WeatherResponse {
-    pub name: String,
-    pub main: WeatherMain,
+/// Coarse weather condition shared by all providers.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherCondition {
+    Clear,
+    PartlyCloudy,
+    Cloudy,
+    Fog,
+    Drizzle,
+    Rain,
+    Snow,
+    Thunderstorm,
+    Unknown,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct WeatherMain {
-    pub temp: f64,
+impl WeatherCondition {
+    pub fn glyph(self) -> &'static str {
+        match self {
+            WeatherCondition::Clear => "☀",
+            WeatherCondition::PartlyCloudy => "⛅",
+            WeatherCondition::Cloudy => "☁",
+            WeatherCondition::Fog => "🌫",
+            WeatherCondition::Drizzle => "🌦",
+            WeatherCondition::Rain => "🌧",
+            WeatherCondition::Snow => "❄",
+            WeatherCondition::Thunderstorm => "⛈",
+            WeatherCondition::Unknown => "?",
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Current conditions, normalized to metric units by the weather provider.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct WeatherInfo {
     pub name: String,
     pub temp_c: f64,
+    pub feels_like_c: f64,
+    /// Relative humidity in percent.
+    pub humidity: f64,
+    /// Wind speed in m/s.
+    pub wind_speed: f64,
+    /// Direction the wind blows from, in degrees.
+    pub wind_direction: f64,
+    /// Sea-level pressure in hPa.
+    pub pressure: f64,
+    pub description: String,
+    pub condition: WeatherCondition,
+}
+
+/// Forecast for one point in time, in the location's time zone.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HourlyForecast {
+    pub time: DateTime<FixedOffset>,
+    pub temp_c: f64,
+    pub condition: WeatherCondition,
+    /// Probability of precipitation in percent.
+    pub precipitation_chance: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DailyForecast {
+    pub date: NaiveDate,
+    pub min_c: f64,
+    pub max_c: f64,
+    pub condition: WeatherCondition,
+    pub precipitation_chance: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Forecast {
+    pub hourly: Vec<HourlyForecast>,
+    pub daily: Vec<DailyForecast>,
+}
+
+impl Forecast {
+    /// Up to `count` hourly points starting with the current hour.
+    pub fn upcoming(&self, now: DateTime<Local>, count: usize) -> &[HourlyForecast] {
+        let start = self
+            .hourly
+            .iter()
+            .position(|point| point.time > now - chrono::Duration::hours(1))
+            .unwrap_or(self.hourly.len());
+        let end = (start + count).min(self.hourly.len());
+        &self.hourly[start..end]
+    }
+}
+
+/// Which forecast the weather panel lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForecastMode {
+    Hourly,
+    Daily,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -39,9 +310,13 @@ pub struct CpuInfo {
     pub len: usize,
     pub frequency: u64,
     pub brand: String,
+    /// Utilization of all cores together, in percent.
+    pub usage: f32,
+    /// Utilization of each logical core, in percent.
+    pub cores: Vec<f32>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     Todo,
     Active,
@@ -50,6 +325,29 @@ pub enum Status {
 }
 
 impl Status {
+    pub const ALL: [Status; 4] = [
+        Status::Todo,
+        Status::Active,
+        Status::Done,
+        Status::Cancelled,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Todo => "Todo",
+            Status::Active => "Active",
+            Status::Done => "Done",
+            Status::Cancelled => "Cancelled",
+        }
+    }
+
+    /// Case-insensitive inverse of [`Status::as_str`].
+    pub fn parse(value: &str) -> Option<Status> {
+        Status::ALL
+            .into_iter()
+            .find(|status| status.as_str().eq_ignore_ascii_case(value.trim()))
+    }
+
     pub fn get_color(&self) -> Color {
         match self {
             self::Status::Active => Color::Yellow,
@@ -60,14 +358,51 @@ impl Status {
     }
 }
 
-//TODO: Добавить дату создания и дату окончания
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl Priority {
+    pub const ALL: [Priority; 3] = [Priority::Low, Priority::Medium, Priority::High];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "Low",
+            Priority::Medium => "Medium",
+            Priority::High => "High",
+        }
+    }
+
+    /// Case-insensitive inverse of [`Priority::as_str`].
+    pub fn parse(value: &str) -> Option<Priority> {
+        Priority::ALL
+            .into_iter()
+            .find(|priority| priority.as_str().eq_ignore_ascii_case(value.trim()))
+    }
+
+    pub fn get_color(&self) -> Color {
+        match self {
+            Priority::Low => Color::Blue,
+            Priority::Medium => Color::Gray,
+            Priority::High => Color::LightRed,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct TODOData {
     pub id: i64,
-    pub title: &'static str,
-    pub message: &'static str,
+    pub title: String,
+    pub message: String,
     pub date: DateTime<Local>,
     pub status: Status,
+    pub priority: Priority,
+    pub due: Option<NaiveDate>,
+    pub tags: Vec<String>,
 }
 
 impl TODOData {
@@ -82,10 +417,46 @@ impl TODOData {
     }
 }
 
+/// Short message shown in the footer, e.g. the outcome of an external edit.
+#[derive(Debug, Clone)]
+pub struct Notice {
+    pub text: String,
+    pub is_error: bool,
+    pub created: Instant,
+}
+
+impl Notice {
+    const LIFETIME: Duration = Duration::from_secs(8);
+
+    pub fn info(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            is_error: false,
+            created: Instant::now(),
+        }
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            is_error: true,
+            created: Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed() >= Self::LIFETIME
+    }
+}
+
 #[derive(Debug)]
 pub struct ListState {
     pub selected: Option<usize>,
     pub items: Vec<TODOData>,
+    /// Ids of tasks marked for a bulk action.
+    pub marked: BTreeSet<i64>,
+    /// Index where the visual (range) selection started, while it is active.
+    pub visual_anchor: Option<usize>,
 }
 
 impl Default for ListState {
@@ -99,6 +470,8 @@ impl ListState {
         Self {
             selected: Some(0),
             items: Vec::new(),
+            marked: BTreeSet::new(),
+            visual_anchor: None,
         }
     }
 
@@ -143,4 +516,64 @@ impl ListState {
     pub fn selected_item(&self) -> Option<&TODOData> {
         self.selected.and_then(|i| self.items.get(i))
     }
+
+    pub fn toggle_mark(&mut self) {
+        if let Some(id) = self.selected_item().map(|item| item.id) {
+            if !self.marked.remove(&id) {
+                self.marked.insert(id);
+            }
+        }
+    }
+
+    /// Start a visual range at the cursor, or commit the active range to the marks.
+    pub fn toggle_visual(&mut self) {
+        if self.visual_anchor.is_some() {
+            self.marked = self.target_ids().into_iter().collect();
+            self.visual_anchor = None;
+        } else {
+            self.visual_anchor = self.selected;
+        }
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.visual_anchor = None;
+    }
+
+    pub fn has_marks(&self) -> bool {
+        !self.marked.is_empty() || self.visual_anchor.is_some()
+    }
+
+    /// Whether the item at `index` is part of the current multi-selection.
+    pub fn is_marked(&self, index: usize) -> bool {
+        let in_range = match (self.visual_anchor, self.selected) {
+            (Some(anchor), Some(selected)) => {
+                (anchor.min(selected)..=anchor.max(selected)).contains(&index)
+            }
+            _ => false,
+        };
+        in_range
+            || self
+                .items
+                .get(index)
+                .is_some_and(|item| self.marked.contains(&item.id))
+    }
+
+    /// Ids a bulk action applies to: the marked tasks and the visual range,
+    /// or the task under the cursor when nothing is marked.
+    pub fn target_ids(&self) -> Vec<i64> {
+        if !self.has_marks() {
+            return self
+                .selected_item()
+                .map(|item| item.id)
+                .into_iter()
+                .collect();
+        }
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.is_marked(*i))
+            .map(|(_, item)| item.id)
+            .collect()
+    }
 }
@@ -0,0 +1,416 @@
+use std::{env, fmt::Display, sync::OnceLock};
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, NaiveDate, TimeZone,
+};
+
+use crate::i18n::tf;
+
+static SETTINGS: OnceLock<UnitSettings> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "c" | "celsius" => Some(TemperatureUnit::Celsius),
+            "f" | "fahrenheit" => Some(TemperatureUnit::Fahrenheit),
+            "k" | "kelvin" => Some(TemperatureUnit::Kelvin),
+            _ => None,
+        }
+    }
+
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpeedUnit {
+    #[default]
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+}
+
+impl SpeedUnit {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "m/s" | "ms" => Some(SpeedUnit::MetersPerSecond),
+            "km/h" | "kmh" => Some(SpeedUnit::KilometersPerHour),
+            "mph" => Some(SpeedUnit::MilesPerHour),
+            _ => None,
+        }
+    }
+
+    /// Speed given in m/s, e.g. `3.4 m/s`.
+    pub fn format(self, meters_per_second: f64) -> String {
+        match self {
+            SpeedUnit::MetersPerSecond => format!("{meters_per_second:.1} m/s"),
+            SpeedUnit::KilometersPerHour => format!("{:.0} km/h", meters_per_second * 3.6),
+            SpeedUnit::MilesPerHour => format!("{:.0} mph", meters_per_second * 2.236_936),
+        }
+    }
+}
+
+/// Whether sizes are shown in powers of 1024 (`GiB`) or of 1000 (`GB`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteUnit {
+    #[default]
+    Binary,
+    Decimal,
+}
+
+impl ByteUnit {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "binary" | "iec" | "gib" => Some(ByteUnit::Binary),
+            "decimal" | "si" | "gb" => Some(ByteUnit::Decimal),
+            _ => None,
+        }
+    }
+
+    fn base(self) -> f64 {
+        match self {
+            ByteUnit::Binary => 1024.0,
+            ByteUnit::Decimal => 1000.0,
+        }
+    }
+
+    fn names(self) -> [&'static str; 6] {
+        match self {
+            ByteUnit::Binary => ["B", "KiB", "MiB", "GiB", "TiB", "PiB"],
+            ByteUnit::Decimal => ["B", "kB", "MB", "GB", "TB", "PB"],
+        }
+    }
+
+    /// Human readable size, e.g. `1.5 GiB`.
+    pub fn format(self, bytes: u64) -> String {
+        let names = self.names();
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= self.base() && unit < names.len() - 1 {
+            value /= self.base();
+            unit += 1;
+        }
+
+        if unit == 0 {
+            format!("{bytes} B")
+        } else if value < 10.0 {
+            format!("{value:.1} {}", names[unit])
+        } else {
+            format!("{value:.0} {}", names[unit])
+        }
+    }
+
+    /// Memory amount given in GiB with a fixed number of `decimals`.
+    pub fn format_gib(self, gib: f64, decimals: usize) -> String {
+        let (value, name) = self.scale_gib(gib);
+        format!("{value:.decimals$} {name}")
+    }
+
+    /// Part of a memory amount, both given in GiB, e.g. `3.1/7.6 GiB`.
+    pub fn format_gib_of(self, used: f64, total: f64, decimals: usize) -> String {
+        let (used, _) = self.scale_gib(used);
+        let (total, name) = self.scale_gib(total);
+        format!("{used:.decimals$}/{total:.decimals$} {name}")
+    }
+
+    fn scale_gib(self, gib: f64) -> (f64, &'static str) {
+        match self {
+            ByteUnit::Binary => (gib, "GiB"),
+            ByteUnit::Decimal => (gib * 1.073_741_824, "GB"),
+        }
+    }
+}
+
+/// Display units and date formats, set once at startup from the config.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitSettings {
+    pub temperature: TemperatureUnit,
+    pub wind_speed: SpeedUnit,
+    pub bytes: ByteUnit,
+    /// strftime format of the header clock.
+    pub clock_format: String,
+    /// strftime format of a time of day without seconds, such as forecast hours.
+    pub time_format: String,
+    /// strftime format of timestamps such as a task's creation time.
+    pub datetime_format: String,
+    /// strftime format of dates such as a task's due date.
+    pub date_format: String,
+}
+
+impl Default for UnitSettings {
+    fn default() -> Self {
+        Self {
+            temperature: TemperatureUnit::Celsius,
+            wind_speed: SpeedUnit::MetersPerSecond,
+            bytes: ByteUnit::Binary,
+            clock_format: "%H:%M:%S".to_string(),
+            time_format: "%H:%M".to_string(),
+            datetime_format: "%d.%m.%y %H:%M".to_string(),
+            date_format: "%d.%m.%y".to_string(),
+        }
+    }
+}
+
+impl UnitSettings {
+    /// Conventions of the user's locale: imperial units in the few
+    /// countries that use them, and the usual date order and clock.
+    pub fn for_locale(measurement: &Locale, time: &Locale) -> Self {
+        let mut settings = Self::default();
+        if matches!(
+            measurement.region(),
+            Some("US" | "LR" | "MM" | "BS" | "KY" | "PW")
+        ) {
+            settings.temperature = TemperatureUnit::Fahrenheit;
+        }
+        if matches!(measurement.region(), Some("US" | "GB" | "LR" | "MM")) {
+            settings.wind_speed = SpeedUnit::MilesPerHour;
+        }
+
+        let (date, clock, time_of_day) = match (time.language(), time.region()) {
+            (_, Some("US" | "PH")) => ("%m/%d/%y", "%I:%M:%S %p", "%I:%M %p"),
+            ("ja" | "zh" | "ko" | "hu" | "lt" | "sv", _) => ("%Y-%m-%d", "%H:%M:%S", "%H:%M"),
+            ("en" | "fr" | "es" | "it" | "pt" | "el" | "ga", _) => {
+                ("%d/%m/%y", "%H:%M:%S", "%H:%M")
+            }
+            _ => return settings,
+        };
+        settings.date_format = date.to_string();
+        settings.clock_format = clock.to_string();
+        settings.time_format = time_of_day.to_string();
+        settings.datetime_format = format!("{date} {time_of_day}");
+        settings
+    }
+}
+
+/// Language and region of a POSIX locale name such as `en_US.UTF-8`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Locale {
+    language: String,
+    region: Option<String>,
+}
+
+impl Locale {
+    pub fn parse(name: &str) -> Self {
+        let name = name.split(['.', '@']).next().unwrap_or_default();
+        let (language, region) = match name.split_once(['_', '-']) {
+            Some((language, region)) => (language, Some(region.to_uppercase())),
+            None => (name, None),
+        };
+        Self {
+            language: language.to_lowercase(),
+            region,
+        }
+    }
+
+    /// Locale of a category such as `LC_TIME`, resolved like libc does:
+    /// `LC_ALL` first, then the category, then `LANG`.
+    pub fn from_env(category: &str) -> Self {
+        ["LC_ALL", category, "LANG"]
+            .into_iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.trim().is_empty())
+            .map(|value| Self::parse(value.trim()))
+            .unwrap_or_default()
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+}
+
+/// Whether `format` is a strftime format chrono can render.
+pub fn is_valid_format(format: &str) -> bool {
+    !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+}
+
+/// Make `settings` the ones used by all formatting helpers. Only the first
+/// call has an effect; until then the defaults apply.
+pub fn init(settings: UnitSettings) {
+    let _ = SETTINGS.set(settings);
+}
+
+pub fn settings() -> &'static UnitSettings {
+    SETTINGS.get_or_init(UnitSettings::default)
+}
+
+/// Human readable size in the configured unit, e.g. `1.5 GiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    settings().bytes.format(bytes)
+}
+
+/// Memory amount given in GiB, e.g. `7.6 GiB`.
+pub fn format_gib(gib: f64, decimals: usize) -> String {
+    settings().bytes.format_gib(gib, decimals)
+}
+
+/// Used and total memory given in GiB, e.g. `3.1/7.6 GiB`.
+pub fn format_gib_of(used: f64, total: f64, decimals: usize) -> String {
+    settings().bytes.format_gib_of(used, total, decimals)
+}
+
+/// Temperature given in °C, in the configured unit, e.g. `21°C`.
+pub fn format_temperature(celsius: f64, decimals: usize) -> String {
+    let unit = settings().temperature;
+    format!("{:.decimals$}{}", unit.convert(celsius), unit.symbol())
+}
+
+/// Wind speed given in m/s, in the configured unit.
+pub fn format_speed(meters_per_second: f64) -> String {
+    settings().wind_speed.format(meters_per_second)
+}
+
+pub fn format_clock<Tz: TimeZone>(time: &DateTime<Tz>) -> String
+where
+    Tz::Offset: Display,
+{
+    time.format(&settings().clock_format).to_string()
+}
+
+pub fn format_time<Tz: TimeZone>(time: &DateTime<Tz>) -> String
+where
+    Tz::Offset: Display,
+{
+    time.format(&settings().time_format).to_string()
+}
+
+pub fn format_datetime<Tz: TimeZone>(time: &DateTime<Tz>) -> String
+where
+    Tz::Offset: Display,
+{
+    time.format(&settings().datetime_format).to_string()
+}
+
+pub fn format_date(date: NaiveDate) -> String {
+    date.format(&settings().date_format).to_string()
+}
+
+/// Throughput in bytes per second, e.g. `12 MiB/s`.
+pub fn format_rate(bytes_per_sec: f64) -> String {
+    format!("{}/s", format_bytes(bytes_per_sec.max(0.0).round() as u64))
+}
+
+/// Compact duration with the two largest units, e.g. `3d 4h`, `2h 15m`, `42s`.
+pub fn format_duration(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let hours = seconds % 86_400 / 3_600;
+    let minutes = seconds % 3_600 / 60;
+
+    if days > 0 {
+        tf("{}d {}h", &[&days, &hours])
+    } else if hours > 0 {
+        tf("{}h {}m", &[&hours, &minutes])
+    } else if minutes > 0 {
+        tf("{}m", &[&minutes])
+    } else {
+        tf("{}s", &[&seconds])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(500 * 1024 * 1024), "500 MiB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024), "1.0 GiB");
+
+        assert_eq!(ByteUnit::Decimal.format(1536), "1.5 kB");
+        assert_eq!(ByteUnit::Decimal.format(1024 * 1024 * 1024), "1.1 GB");
+        assert_eq!(ByteUnit::Decimal.format_gib(1.0, 2), "1.07 GB");
+        assert_eq!(ByteUnit::Binary.format_gib(7.55, 1), "7.5 GiB");
+        assert_eq!(format_gib_of(3.12, 7.6, 1), "3.1/7.6 GiB");
+    }
+
+    #[test]
+    fn test_temperature_and_speed_units() {
+        assert_eq!(
+            TemperatureUnit::parse("F"),
+            Some(TemperatureUnit::Fahrenheit)
+        );
+        assert_eq!(TemperatureUnit::Fahrenheit.convert(100.0), 212.0);
+        assert_eq!(TemperatureUnit::Kelvin.convert(-273.15), 0.0);
+        assert_eq!(format_temperature(21.4, 0), "21°C");
+
+        assert_eq!(SpeedUnit::parse("km/h"), Some(SpeedUnit::KilometersPerHour));
+        assert_eq!(SpeedUnit::KilometersPerHour.format(10.0), "36 km/h");
+        assert_eq!(SpeedUnit::MilesPerHour.format(10.0), "22 mph");
+        assert_eq!(format_speed(3.42), "3.4 m/s");
+    }
+
+    #[test]
+    fn test_locale_defaults() {
+        let locale = Locale::parse("en_US.UTF-8");
+        assert_eq!((locale.language(), locale.region()), ("en", Some("US")));
+        assert_eq!(Locale::parse("C"), Locale::parse("C.UTF-8"));
+
+        let us = UnitSettings::for_locale(&locale, &locale);
+        assert_eq!(us.temperature, TemperatureUnit::Fahrenheit);
+        assert_eq!(us.wind_speed, SpeedUnit::MilesPerHour);
+        assert_eq!(us.datetime_format, "%m/%d/%y %I:%M %p");
+        assert_eq!(us.time_format, "%I:%M %p");
+
+        let de = Locale::parse("de_DE.UTF-8");
+        assert_eq!(UnitSettings::for_locale(&de, &de), UnitSettings::default());
+        let sv = Locale::parse("sv_SE");
+        assert_eq!(UnitSettings::for_locale(&sv, &sv).date_format, "%Y-%m-%d");
+    }
+
+    #[test]
+    fn test_date_formats() {
+        let time = chrono::Local
+            .with_ymd_and_hms(2026, 10, 18, 9, 5, 3)
+            .unwrap();
+        assert_eq!(format_clock(&time), "09:05:03");
+        assert_eq!(format_time(&time), "09:05");
+        assert_eq!(format_datetime(&time), "18.10.26 09:05");
+        assert_eq!(format_date(time.date_naive()), "18.10.26");
+
+        assert!(is_valid_format("%d/%m/%Y %I:%M %p"));
+        assert!(!is_valid_format("%d.%m.%"));
+        assert!(!is_valid_format("%Q"));
+    }
+
+    #[test]
+    fn test_format_rate() {
+        assert_eq!(format_rate(2048.0), "2.0 KiB/s");
+        assert_eq!(format_rate(-1.0), "0 B/s");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(600), "10m");
+        assert_eq!(format_duration(2 * 3_600 + 15 * 60), "2h 15m");
+        assert_eq!(format_duration(3 * 86_400 + 4 * 3_600 + 59), "3d 4h");
+    }
+}
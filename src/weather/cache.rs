@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use super::Location;
+use crate::types::{Forecast, GeoData, WeatherInfo};
+
+/// Last fetched data of one location, stored in the database so the header
+/// is filled right away on startup and while offline.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CachedWeather {
+    pub geo: GeoData,
+    pub weather: Option<WeatherInfo>,
+    pub forecast: Option<Forecast>,
+}
+
+impl CachedWeather {
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// `None` for entries written by an incompatible version.
+    pub fn from_json(body: &str) -> Option<Self> {
+        serde_json::from_str(body).ok()
+    }
+}
+
+/// Cache entry of a saved location, or of the location found by public IP.
+/// The label is part of the key since the cached place name is shown.
+pub fn cache_key(location: Option<&Location>) -> String {
+    let (kind, name, place) = match location {
+        None => return "ip".to_string(),
+        Some(Location::City { name, query }) => ("city", name, query.to_lowercase()),
+        Some(Location::Coordinates {
+            name,
+            latitude,
+            longitude,
+        }) => ("coordinates", name, format!("{latitude:.4},{longitude:.4}")),
+    };
+    match name {
+        Some(name) => format!("{kind}:{name}={place}"),
+        None => format!("{kind}:{place}"),
+    }
+}
+
+/// How long data fetched at `fetched_at` stays fresh; zero once expired.
+pub fn time_to_live(fetched_at: DateTime<Local>, now: DateTime<Local>, ttl: Duration) -> Duration {
+    let age = (now - fetched_at).to_std().unwrap_or_default();
+    ttl.saturating_sub(age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DailyForecast, HourlyForecast, WeatherCondition};
+    use chrono::{NaiveDate, TimeZone};
+
+    #[test]
+    fn test_json_round_trip() {
+        let time = chrono::FixedOffset::east_opt(3 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 10, 18, 12, 0, 0)
+            .unwrap();
+        let cached = CachedWeather {
+            geo: GeoData {
+                latitude: 55.75,
+                longitude: 37.62,
+                city: Some("Moscow".to_string()),
+            },
+            weather: Some(WeatherInfo {
+                name: "Moscow".to_string(),
+                temp_c: 7.5,
+                feels_like_c: 5.0,
+                humidity: 80.0,
+                wind_speed: 3.2,
+                wind_direction: 270.0,
+                pressure: 1012.0,
+                description: "light rain".to_string(),
+                condition: WeatherCondition::Rain,
+            }),
+            forecast: Some(Forecast {
+                hourly: vec![HourlyForecast {
+                    time,
+                    temp_c: 8.0,
+                    condition: WeatherCondition::Cloudy,
+                    precipitation_chance: None,
+                }],
+                daily: vec![DailyForecast {
+                    date: NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+                    min_c: 3.0,
+                    max_c: 9.0,
+                    condition: WeatherCondition::Rain,
+                    precipitation_chance: Some(70.0),
+                }],
+            }),
+        };
+
+        let body = cached.to_json().unwrap();
+        assert_eq!(CachedWeather::from_json(&body), Some(cached));
+        assert_eq!(CachedWeather::from_json("{\"geo\": 1}"), None);
+    }
+
+    #[test]
+    fn test_cache_key_and_ttl() {
+        assert_eq!(cache_key(None), "ip");
+        assert_eq!(
+            cache_key(Some(&Location::parse("Berlin"))),
+            cache_key(Some(&Location::parse("berlin")))
+        );
+        assert_eq!(
+            cache_key(Some(&Location::parse("Home=Berlin"))),
+            "city:Home=berlin"
+        );
+        assert_eq!(
+            cache_key(Some(&Location::parse("55.75,37.62"))),
+            "coordinates:55.7500,37.6200"
+        );
+
+        let now = Local::now();
+        let ttl = Duration::from_secs(600);
+        let fetched_at = now - chrono::Duration::seconds(100);
+        assert_eq!(time_to_live(fetched_at, now, ttl), Duration::from_secs(500));
+        assert_eq!(
+            time_to_live(now - chrono::Duration::hours(1), now, ttl),
+            Duration::ZERO
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::{get_json, WeatherError};
+use crate::types::GeoData;
+
+const GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com";
+
+/// A location set in the config instead of looking it up from the public IP.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Location {
+    /// `query` is resolved through the Open-Meteo geocoding API; `name`
+    /// replaces the found place name as the label.
+    City { name: Option<String>, query: String },
+    Coordinates {
+        name: Option<String>,
+        latitude: f64,
+        longitude: f64,
+    },
+}
+
+#[derive(Deserialize, Debug)]
+struct GeocodingResponse {
+    #[serde(default)]
+    results: Vec<GeocodingResult>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GeocodingResult {
+    name: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Location {
+    /// Parse `TUITASK_LOCATION`: entries separated by `;`, each a city name
+    /// or `lat,lon`, optionally prefixed with `Name=`.
+    pub fn parse_list(value: &str) -> Vec<Location> {
+        value
+            .split(';')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(Location::parse)
+            .collect()
+    }
+
+    pub fn parse(entry: &str) -> Location {
+        let (name, place) = match entry.split_once('=') {
+            Some((name, place)) => (Some(name.trim().to_string()), place.trim()),
+            None => (None, entry.trim()),
+        };
+        match parse_coordinates(place) {
+            Some((latitude, longitude)) => Location::Coordinates {
+                name,
+                latitude,
+                longitude,
+            },
+            None => Location::City {
+                name,
+                query: place.to_string(),
+            },
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Location::City {
+                name: Some(name), ..
+            }
+            | Location::Coordinates {
+                name: Some(name), ..
+            } => name.clone(),
+            Location::City { name: None, query } => query.clone(),
+            Location::Coordinates {
+                latitude,
+                longitude,
+                ..
+            } => format!("{latitude:.2}, {longitude:.2}"),
+        }
+    }
+
+    /// Coordinates of this location; only city names need a request.
+    pub async fn resolve(&self, client: &Client) -> Result<GeoData, WeatherError> {
+        self.resolve_with(client, GEOCODING_URL).await
+    }
+
+    async fn resolve_with(&self, client: &Client, base_url: &str) -> Result<GeoData, WeatherError> {
+        match self {
+            Location::City { name, query } => {
+                let url = format!("{base_url}/v1/search");
+                let params = [("name", query.clone()), ("count", "1".to_string())];
+                let response: GeocodingResponse = get_json(client, &url, &params).await?;
+                let found = response
+                    .results
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| WeatherError::UnknownLocation(query.clone()))?;
+                Ok(GeoData {
+                    latitude: found.latitude,
+                    longitude: found.longitude,
+                    city: Some(name.clone().unwrap_or(found.name)),
+                })
+            }
+            Location::Coordinates {
+                name,
+                latitude,
+                longitude,
+            } => Ok(GeoData {
+                latitude: *latitude,
+                longitude: *longitude,
+                city: name.clone(),
+            }),
+        }
+    }
+}
+
+fn parse_coordinates(text: &str) -> Option<(f64, f64)> {
+    let (latitude, longitude) = text.split_once(',')?;
+    let latitude: f64 = latitude.trim().parse().ok()?;
+    let longitude: f64 = longitude.trim().parse().ok()?;
+    ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude))
+        .then_some((latitude, longitude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather::mock;
+
+    #[test]
+    fn test_parse_list() {
+        let locations =
+            Location::parse_list("Berlin; Home=52.52, 13.41 ;;-33.9,151.2;Paris, FR; Mum = Lyon");
+        assert_eq!(
+            locations,
+            vec![
+                Location::City {
+                    name: None,
+                    query: "Berlin".to_string(),
+                },
+                Location::Coordinates {
+                    name: Some("Home".to_string()),
+                    latitude: 52.52,
+                    longitude: 13.41,
+                },
+                Location::Coordinates {
+                    name: None,
+                    latitude: -33.9,
+                    longitude: 151.2,
+                },
+                Location::City {
+                    name: None,
+                    query: "Paris, FR".to_string(),
+                },
+                Location::City {
+                    name: Some("Mum".to_string()),
+                    query: "Lyon".to_string(),
+                },
+            ]
+        );
+        assert_eq!(locations[0].label(), "Berlin");
+        assert_eq!(locations[1].label(), "Home");
+        assert_eq!(locations[2].label(), "-33.90, 151.20");
+        assert_eq!(locations[4].label(), "Mum");
+        assert!(matches!(Location::parse("95,10"), Location::City { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_coordinates_need_no_request() {
+        let location = Location::parse("Home=52.52,13.41");
+        let geo = location
+            .resolve_with(&Client::new(), "http://127.0.0.1:9")
+            .await
+            .unwrap();
+        assert_eq!((geo.latitude, geo.longitude), (52.52, 13.41));
+        assert_eq!(geo.city.as_deref(), Some("Home"));
+    }
+
+    #[tokio::test]
+    async fn test_geocode_city() {
+        let body = r#"{"results": [{"id": 2950159, "name": "Berlin", "latitude": 52.52437,
+                       "longitude": 13.41053, "country": "Germany"}]}"#;
+        let (base_url, request) = mock::serve_once(200, body).await;
+
+        let geo = Location::parse("berlin")
+            .resolve_with(&Client::new(), &base_url)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            request.await.unwrap(),
+            "GET /v1/search?name=berlin&count=1 HTTP/1.1"
+        );
+        assert_eq!((geo.latitude, geo.longitude), (52.52437, 13.41053));
+        assert_eq!(geo.city.as_deref(), Some("Berlin"));
+    }
+
+    #[tokio::test]
+    async fn test_geocode_named_city() {
+        let body = r#"{"results": [{"name": "Berlin", "latitude": 52.52, "longitude": 13.41}]}"#;
+        let (base_url, request) = mock::serve_once(200, body).await;
+
+        let geo = Location::parse("Home=Berlin")
+            .resolve_with(&Client::new(), &base_url)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            request.await.unwrap(),
+            "GET /v1/search?name=Berlin&count=1 HTTP/1.1"
+        );
+        assert_eq!((geo.latitude, geo.longitude), (52.52, 13.41));
+        assert_eq!(geo.city.as_deref(), Some("Home"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_city() {
+        let (base_url, _) = mock::serve_once(200, r#"{"generationtime_ms": 0.5}"#).await;
+        let result = Location::parse("Atlantis")
+            .resolve_with(&Client::new(), &base_url)
+            .await;
+        assert!(matches!(result, Err(WeatherError::UnknownLocation(name)) if name == "Atlantis"));
+    }
+}
@@ -0,0 +1,238 @@
+//! Current weather from a configurable service. Every provider normalizes
+//! its response into [`WeatherInfo`] with metric units.
+
+mod cache;
+mod location;
+mod open_meteo;
+mod openweathermap;
+mod schedule;
+
+use std::{fmt, future::Future};
+
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+
+pub use cache::{cache_key, time_to_live, CachedWeather};
+pub use location::Location;
+pub use open_meteo::OpenMeteo;
+pub use openweathermap::OpenWeatherMap;
+pub use schedule::Backoff;
+
+use crate::types::{Forecast, GeoData, WeatherInfo};
+
+#[derive(Debug)]
+pub enum WeatherError {
+    Request(String),
+    Status(u16),
+    MissingKey(&'static str),
+    UnknownLocation(String),
+}
+
+impl fmt::Display for WeatherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeatherError::Request(msg) => write!(f, "Weather request failed: {}", msg),
+            WeatherError::Status(code) => write!(f, "Weather service answered HTTP {}", code),
+            WeatherError::MissingKey(provider) => {
+                write!(f, "{} needs WEATHER_API_KEY to be set", provider)
+            }
+            WeatherError::UnknownLocation(name) => write!(f, "Location '{}' not found", name),
+        }
+    }
+}
+
+impl std::error::Error for WeatherError {}
+
+impl From<reqwest::Error> for WeatherError {
+    fn from(error: reqwest::Error) -> Self {
+        match error.status() {
+            Some(status) => WeatherError::Status(status.as_u16()),
+            None => WeatherError::Request(error.to_string()),
+        }
+    }
+}
+
+pub trait WeatherProvider {
+    fn name(&self) -> &'static str;
+
+    /// Current conditions at `location`.
+    fn current(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> impl Future<Output = Result<WeatherInfo, WeatherError>> + Send;
+
+    /// Hourly and daily forecast for the next days at `location`.
+    fn forecast(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> impl Future<Output = Result<Forecast, WeatherError>> + Send;
+}
+
+/// Service selected with `TUITASK_WEATHER_PROVIDER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderKind {
+    OpenWeatherMap,
+    OpenMeteo,
+}
+
+impl ProviderKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "openweathermap" | "owm" => Some(ProviderKind::OpenWeatherMap),
+            "open-meteo" | "openmeteo" => Some(ProviderKind::OpenMeteo),
+            _ => None,
+        }
+    }
+}
+
+/// The configured provider.
+#[derive(Debug, Clone)]
+pub enum Provider {
+    OpenWeatherMap(OpenWeatherMap),
+    OpenMeteo(OpenMeteo),
+}
+
+impl Provider {
+    /// Without an explicit choice OpenWeatherMap is used when an API key is
+    /// set and the keyless Open-Meteo otherwise.
+    pub fn new(kind: Option<ProviderKind>, api_key: Option<&str>) -> Result<Self, WeatherError> {
+        match (kind, api_key) {
+            (Some(ProviderKind::OpenWeatherMap) | None, Some(key)) => {
+                Ok(Provider::OpenWeatherMap(OpenWeatherMap::new(key)))
+            }
+            (Some(ProviderKind::OpenWeatherMap), None) => {
+                Err(WeatherError::MissingKey(OpenWeatherMap::NAME))
+            }
+            (Some(ProviderKind::OpenMeteo) | None, _) => Ok(Provider::OpenMeteo(OpenMeteo::new())),
+        }
+    }
+}
+
+impl WeatherProvider for Provider {
+    fn name(&self) -> &'static str {
+        match self {
+            Provider::OpenWeatherMap(provider) => provider.name(),
+            Provider::OpenMeteo(provider) => provider.name(),
+        }
+    }
+
+    async fn current(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> Result<WeatherInfo, WeatherError> {
+        match self {
+            Provider::OpenWeatherMap(provider) => provider.current(client, location).await,
+            Provider::OpenMeteo(provider) => provider.current(client, location).await,
+        }
+    }
+
+    async fn forecast(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> Result<Forecast, WeatherError> {
+        match self {
+            Provider::OpenWeatherMap(provider) => provider.forecast(client, location).await,
+            Provider::OpenMeteo(provider) => provider.forecast(client, location).await,
+        }
+    }
+}
+
+async fn get_json<T: DeserializeOwned>(
+    client: &Client,
+    url: &str,
+    query: &[(&str, String)],
+) -> Result<T, WeatherError> {
+    Ok(client
+        .get(url)
+        .query(query)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<T>()
+        .await?)
+}
+
+/// Name shown for a location the provider does not name itself.
+fn location_name(location: &GeoData) -> String {
+    location
+        .city
+        .clone()
+        .unwrap_or_else(|| format!("{:.2}, {:.2}", location.latitude, location.longitude))
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        sync::oneshot,
+    };
+
+    /// Answer a single request with `status` and a JSON `body`. Returns the
+    /// base URL and the request line (`GET /path?query HTTP/1.1`).
+    pub async fn serve_once(
+        status: u16,
+        body: &'static str,
+    ) -> (String, oneshot::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let request = String::from_utf8_lossy(&request);
+            let _ = tx.send(request.lines().next().unwrap_or_default().to_string());
+
+            let response = format!(
+                "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        (base_url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_selection() {
+        assert!(matches!(
+            Provider::new(None, Some("key")),
+            Ok(Provider::OpenWeatherMap(_))
+        ));
+        assert!(matches!(
+            Provider::new(None, None),
+            Ok(Provider::OpenMeteo(_))
+        ));
+        assert!(matches!(
+            Provider::new(Some(ProviderKind::OpenMeteo), Some("key")),
+            Ok(Provider::OpenMeteo(_))
+        ));
+        assert!(matches!(
+            Provider::new(Some(ProviderKind::OpenWeatherMap), None),
+            Err(WeatherError::MissingKey(_))
+        ));
+        assert_eq!(
+            ProviderKind::parse(" Open-Meteo "),
+            Some(ProviderKind::OpenMeteo)
+        );
+        assert_eq!(ProviderKind::parse("yahoo"), None);
+    }
+}
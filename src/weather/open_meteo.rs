@@ -0,0 +1,321 @@
+use chrono::{DateTime, FixedOffset};
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::{get_json, location_name, WeatherError, WeatherProvider};
+use crate::types::{
+    DailyForecast, Forecast, GeoData, HourlyForecast, WeatherCondition, WeatherInfo,
+};
+
+/// <https://open-meteo.com/en/docs>, free and without an API key.
+#[derive(Debug, Clone)]
+pub struct OpenMeteo {
+    base_url: String,
+}
+
+const CURRENT_FIELDS: &str = "temperature_2m,apparent_temperature,relative_humidity_2m,\
+                              weather_code,pressure_msl,wind_speed_10m,wind_direction_10m";
+
+const FORECAST_DAYS: u8 = 7;
+const HOURLY_FIELDS: &str = "temperature_2m,weather_code,precipitation_probability";
+const DAILY_FIELDS: &str =
+    "weather_code,temperature_2m_max,temperature_2m_min,precipitation_probability_max";
+
+#[derive(Deserialize, Debug)]
+struct ForecastResponse {
+    utc_offset_seconds: i32,
+    hourly: Hourly,
+    daily: Daily,
+}
+
+/// Columns of equal length; values are `null` where the model has no data.
+#[derive(Deserialize, Debug)]
+struct Hourly {
+    time: Vec<i64>,
+    temperature_2m: Vec<Option<f64>>,
+    weather_code: Vec<Option<u8>>,
+    #[serde(default)]
+    precipitation_probability: Vec<Option<f64>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Daily {
+    time: Vec<i64>,
+    weather_code: Vec<Option<u8>>,
+    temperature_2m_max: Vec<Option<f64>>,
+    temperature_2m_min: Vec<Option<f64>>,
+    #[serde(default)]
+    precipitation_probability_max: Vec<Option<f64>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Response {
+    current: Current,
+}
+
+#[derive(Deserialize, Debug)]
+struct Current {
+    temperature_2m: f64,
+    apparent_temperature: f64,
+    relative_humidity_2m: f64,
+    weather_code: u8,
+    pressure_msl: f64,
+    wind_speed_10m: f64,
+    wind_direction_10m: f64,
+}
+
+impl OpenMeteo {
+    pub const NAME: &'static str = "Open-Meteo";
+    const BASE_URL: &'static str = "https://api.open-meteo.com";
+
+    pub fn new() -> Self {
+        Self::with_base_url(Self::BASE_URL)
+    }
+
+    pub fn with_base_url(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl Default for OpenMeteo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WeatherProvider for OpenMeteo {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    async fn current(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> Result<WeatherInfo, WeatherError> {
+        let url = format!("{}/v1/forecast", self.base_url);
+        let query = [
+            ("latitude", location.latitude.to_string()),
+            ("longitude", location.longitude.to_string()),
+            ("current", CURRENT_FIELDS.to_string()),
+            ("wind_speed_unit", "ms".to_string()),
+        ];
+        let current = get_json::<Response>(client, &url, &query).await?.current;
+        let (condition, description) = wmo_code(current.weather_code);
+
+        Ok(WeatherInfo {
+            name: location_name(location),
+            temp_c: current.temperature_2m,
+            feels_like_c: current.apparent_temperature,
+            humidity: current.relative_humidity_2m,
+            wind_speed: current.wind_speed_10m,
+            wind_direction: current.wind_direction_10m,
+            pressure: current.pressure_msl,
+            description: description.to_string(),
+            condition,
+        })
+    }
+
+    async fn forecast(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> Result<Forecast, WeatherError> {
+        let url = format!("{}/v1/forecast", self.base_url);
+        let query = [
+            ("latitude", location.latitude.to_string()),
+            ("longitude", location.longitude.to_string()),
+            ("hourly", HOURLY_FIELDS.to_string()),
+            ("daily", DAILY_FIELDS.to_string()),
+            ("forecast_days", FORECAST_DAYS.to_string()),
+            ("timezone", "auto".to_string()),
+            ("timeformat", "unixtime".to_string()),
+        ];
+        let resp: ForecastResponse = get_json(client, &url, &query).await?;
+        let offset = FixedOffset::east_opt(resp.utc_offset_seconds)
+            .ok_or_else(|| WeatherError::Request("invalid UTC offset".to_string()))?;
+        let local =
+            |ts: i64| DateTime::from_timestamp(ts, 0).map(|time| time.with_timezone(&offset));
+        let condition =
+            |code: Option<u8>| code.map_or(WeatherCondition::Unknown, |c| wmo_code(c).0);
+
+        let hourly = &resp.hourly;
+        let hourly = hourly
+            .time
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ts)| {
+                Some(HourlyForecast {
+                    time: local(*ts)?,
+                    temp_c: (*hourly.temperature_2m.get(i)?)?,
+                    condition: condition(hourly.weather_code.get(i).copied().flatten()),
+                    precipitation_chance: hourly
+                        .precipitation_probability
+                        .get(i)
+                        .copied()
+                        .flatten(),
+                })
+            })
+            .collect();
+
+        let daily = &resp.daily;
+        let daily = daily
+            .time
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ts)| {
+                Some(DailyForecast {
+                    date: local(*ts)?.date_naive(),
+                    min_c: (*daily.temperature_2m_min.get(i)?)?,
+                    max_c: (*daily.temperature_2m_max.get(i)?)?,
+                    condition: condition(daily.weather_code.get(i).copied().flatten()),
+                    precipitation_chance: daily
+                        .precipitation_probability_max
+                        .get(i)
+                        .copied()
+                        .flatten(),
+                })
+            })
+            .collect();
+
+        Ok(Forecast { hourly, daily })
+    }
+}
+
+/// WMO weather interpretation codes as used by Open-Meteo.
+fn wmo_code(code: u8) -> (WeatherCondition, &'static str) {
+    match code {
+        0 => (WeatherCondition::Clear, "clear sky"),
+        1 => (WeatherCondition::PartlyCloudy, "mainly clear"),
+        2 => (WeatherCondition::PartlyCloudy, "partly cloudy"),
+        3 => (WeatherCondition::Cloudy, "overcast"),
+        45 | 48 => (WeatherCondition::Fog, "fog"),
+        51..=57 => (WeatherCondition::Drizzle, "drizzle"),
+        61 | 80 => (WeatherCondition::Rain, "light rain"),
+        63 | 81 => (WeatherCondition::Rain, "rain"),
+        65 | 82 => (WeatherCondition::Rain, "heavy rain"),
+        66 | 67 => (WeatherCondition::Rain, "freezing rain"),
+        71..=77 | 85 | 86 => (WeatherCondition::Snow, "snow"),
+        95..=99 => (WeatherCondition::Thunderstorm, "thunderstorm"),
+        _ => (WeatherCondition::Unknown, "unknown"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather::mock;
+
+    const RESPONSE: &str = r#"{
+        "latitude": 52.52, "longitude": 13.419998,
+        "current_units": {"temperature_2m": "°C"},
+        "current": {"time": "2026-10-18T12:00", "interval": 900,
+                    "temperature_2m": 9.8, "apparent_temperature": 7.2,
+                    "relative_humidity_2m": 76, "weather_code": 3, "pressure_msl": 1019.4,
+                    "wind_speed_10m": 3.1, "wind_direction_10m": 270}
+    }"#;
+
+    #[tokio::test]
+    async fn test_current_weather() {
+        let (base_url, request) = mock::serve_once(200, RESPONSE).await;
+        let provider = OpenMeteo::with_base_url(&base_url);
+        let location = GeoData {
+            latitude: 52.52,
+            longitude: 13.41,
+            city: Some("Berlin".to_string()),
+        };
+
+        let weather = provider.current(&Client::new(), &location).await.unwrap();
+
+        let request = request.await.unwrap();
+        assert!(request.starts_with("GET /v1/forecast?latitude=52.52&longitude=13.41&current="));
+        assert!(request.contains("wind_speed_unit=ms"));
+        assert_eq!(weather.name, "Berlin");
+        assert_eq!(weather.temp_c, 9.8);
+        assert_eq!(weather.feels_like_c, 7.2);
+        assert_eq!(weather.humidity, 76.0);
+        assert_eq!(weather.pressure, 1019.4);
+        assert_eq!(weather.wind_speed, 3.1);
+        assert_eq!(weather.wind_direction, 270.0);
+        assert_eq!(weather.description, "overcast");
+        assert_eq!(weather.condition, WeatherCondition::Cloudy);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_response() {
+        let (base_url, _) = mock::serve_once(200, r#"{"error": true}"#).await;
+        let location = GeoData {
+            latitude: 0.0,
+            longitude: 0.0,
+            city: None,
+        };
+
+        let result = OpenMeteo::with_base_url(&base_url)
+            .current(&Client::new(), &location)
+            .await;
+        assert!(matches!(result, Err(WeatherError::Request(_))));
+    }
+
+    #[tokio::test]
+    async fn test_forecast() {
+        // 2026-10-18 00:00 and 01:00 in UTC+2, two days starting at local midnight.
+        let body = r#"{
+            "utc_offset_seconds": 7200,
+            "hourly": {"time": [1792274400, 1792278000],
+                       "temperature_2m": [8.5, null],
+                       "weather_code": [61, 0],
+                       "precipitation_probability": [70, 10]},
+            "daily": {"time": [1792274400, 1792360800],
+                      "weather_code": [63, 1],
+                      "temperature_2m_max": [12.0, 14.5],
+                      "temperature_2m_min": [6.1, 5.0],
+                      "precipitation_probability_max": [90, null]}
+        }"#;
+        let (base_url, request) = mock::serve_once(200, body).await;
+        let location = GeoData {
+            latitude: 52.52,
+            longitude: 13.41,
+            city: None,
+        };
+
+        let forecast = OpenMeteo::with_base_url(&base_url)
+            .forecast(&Client::new(), &location)
+            .await
+            .unwrap();
+
+        let request = request.await.unwrap();
+        assert!(request.contains("timeformat=unixtime"), "{request}");
+        assert!(request.contains("forecast_days=7"));
+
+        assert_eq!(
+            forecast.hourly.len(),
+            1,
+            "hours without a temperature are skipped"
+        );
+        let hour = &forecast.hourly[0];
+        assert_eq!(hour.time.to_rfc3339(), "2026-10-18T00:00:00+02:00");
+        assert_eq!(hour.temp_c, 8.5);
+        assert_eq!(hour.condition, WeatherCondition::Rain);
+        assert_eq!(hour.precipitation_chance, Some(70.0));
+
+        assert_eq!(forecast.daily.len(), 2);
+        assert_eq!(forecast.daily[0].date.to_string(), "2026-10-18");
+        assert_eq!(
+            (forecast.daily[0].min_c, forecast.daily[0].max_c),
+            (6.1, 12.0)
+        );
+        assert_eq!(forecast.daily[1].date.to_string(), "2026-10-19");
+        assert_eq!(forecast.daily[1].condition, WeatherCondition::PartlyCloudy);
+        assert_eq!(forecast.daily[1].precipitation_chance, None);
+    }
+
+    #[test]
+    fn test_wmo_codes() {
+        assert_eq!(wmo_code(0).0, WeatherCondition::Clear);
+        assert_eq!(wmo_code(82).0, WeatherCondition::Rain);
+        assert_eq!(wmo_code(86).0, WeatherCondition::Snow);
+        assert_eq!(wmo_code(200).0, WeatherCondition::Unknown);
+    }
+}
@@ -0,0 +1,305 @@
+use chrono::{DateTime, FixedOffset, Timelike};
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::{get_json, WeatherError, WeatherProvider};
+use crate::types::{
+    DailyForecast, Forecast, GeoData, HourlyForecast, WeatherCondition, WeatherInfo,
+};
+
+/// <https://openweathermap.org/current>, needs an API key.
+#[derive(Debug, Clone)]
+pub struct OpenWeatherMap {
+    base_url: String,
+    api_key: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Response {
+    name: String,
+    main: Main,
+    #[serde(default)]
+    wind: Wind,
+    #[serde(default)]
+    weather: Vec<Condition>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Main {
+    temp: f64,
+    feels_like: f64,
+    pressure: f64,
+    humidity: f64,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct Wind {
+    speed: f64,
+    #[serde(default)]
+    deg: f64,
+}
+
+/// The free 5 day forecast comes in 3-hour steps.
+#[derive(Deserialize, Debug)]
+struct ForecastResponse {
+    list: Vec<ForecastEntry>,
+    city: City,
+}
+
+#[derive(Deserialize, Debug)]
+struct ForecastEntry {
+    dt: i64,
+    main: ForecastMain,
+    #[serde(default)]
+    weather: Vec<Condition>,
+    /// Probability of precipitation, 0 to 1.
+    #[serde(default)]
+    pop: Option<f64>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ForecastMain {
+    temp: f64,
+}
+
+#[derive(Deserialize, Debug)]
+struct City {
+    /// Offset from UTC in seconds.
+    timezone: i32,
+}
+
+#[derive(Deserialize, Debug)]
+struct Condition {
+    id: u16,
+    description: String,
+}
+
+impl OpenWeatherMap {
+    pub const NAME: &'static str = "OpenWeatherMap";
+    const BASE_URL: &'static str = "https://api.openweathermap.org";
+
+    pub fn new(api_key: &str) -> Self {
+        Self::with_base_url(Self::BASE_URL, api_key)
+    }
+
+    pub fn with_base_url(base_url: &str, api_key: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+        }
+    }
+}
+
+impl WeatherProvider for OpenWeatherMap {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    async fn current(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> Result<WeatherInfo, WeatherError> {
+        let url = format!("{}/data/2.5/weather", self.base_url);
+        let query = [
+            ("lat", location.latitude.to_string()),
+            ("lon", location.longitude.to_string()),
+            ("appid", self.api_key.clone()),
+            ("units", "metric".to_string()),
+        ];
+        let resp: Response = get_json(client, &url, &query).await?;
+        let condition = resp.weather.first();
+
+        Ok(WeatherInfo {
+            name: resp.name,
+            temp_c: resp.main.temp,
+            feels_like_c: resp.main.feels_like,
+            humidity: resp.main.humidity,
+            wind_speed: resp.wind.speed,
+            wind_direction: resp.wind.deg,
+            pressure: resp.main.pressure,
+            description: condition.map(|c| c.description.clone()).unwrap_or_default(),
+            condition: condition.map_or(WeatherCondition::Unknown, |c| condition_from_id(c.id)),
+        })
+    }
+
+    async fn forecast(
+        &self,
+        client: &Client,
+        location: &GeoData,
+    ) -> Result<Forecast, WeatherError> {
+        let url = format!("{}/data/2.5/forecast", self.base_url);
+        let query = [
+            ("lat", location.latitude.to_string()),
+            ("lon", location.longitude.to_string()),
+            ("appid", self.api_key.clone()),
+            ("units", "metric".to_string()),
+        ];
+        let resp: ForecastResponse = get_json(client, &url, &query).await?;
+        let offset = FixedOffset::east_opt(resp.city.timezone)
+            .ok_or_else(|| WeatherError::Request("invalid UTC offset".to_string()))?;
+
+        let hourly: Vec<HourlyForecast> = resp
+            .list
+            .iter()
+            .filter_map(|entry| {
+                Some(HourlyForecast {
+                    time: DateTime::from_timestamp(entry.dt, 0)?.with_timezone(&offset),
+                    temp_c: entry.main.temp,
+                    condition: entry
+                        .weather
+                        .first()
+                        .map_or(WeatherCondition::Unknown, |c| condition_from_id(c.id)),
+                    precipitation_chance: entry.pop.map(|pop| pop * 100.0),
+                })
+            })
+            .collect();
+        let daily = daily_from_hourly(&hourly);
+
+        Ok(Forecast { hourly, daily })
+    }
+}
+
+/// Summarize 3-hour steps per local day. The condition is taken from the
+/// step closest to noon.
+fn daily_from_hourly(hourly: &[HourlyForecast]) -> Vec<DailyForecast> {
+    let mut daily: Vec<(DailyForecast, u32)> = Vec::new();
+    for point in hourly {
+        let date = point.time.date_naive();
+        let from_noon = point.time.hour().abs_diff(12);
+        match daily.last_mut() {
+            Some((day, noon_distance)) if day.date == date => {
+                day.min_c = day.min_c.min(point.temp_c);
+                day.max_c = day.max_c.max(point.temp_c);
+                day.precipitation_chance =
+                    match (day.precipitation_chance, point.precipitation_chance) {
+                        (Some(a), Some(b)) => Some(a.max(b)),
+                        (a, b) => a.or(b),
+                    };
+                if from_noon < *noon_distance {
+                    day.condition = point.condition;
+                    *noon_distance = from_noon;
+                }
+            }
+            _ => daily.push((
+                DailyForecast {
+                    date,
+                    min_c: point.temp_c,
+                    max_c: point.temp_c,
+                    condition: point.condition,
+                    precipitation_chance: point.precipitation_chance,
+                },
+                from_noon,
+            )),
+        }
+    }
+    daily.into_iter().map(|(day, _)| day).collect()
+}
+
+/// Condition groups of <https://openweathermap.org/weather-conditions>.
+fn condition_from_id(id: u16) -> WeatherCondition {
+    match id {
+        200..=299 => WeatherCondition::Thunderstorm,
+        300..=399 => WeatherCondition::Drizzle,
+        500..=599 => WeatherCondition::Rain,
+        600..=699 => WeatherCondition::Snow,
+        700..=799 => WeatherCondition::Fog,
+        800 => WeatherCondition::Clear,
+        801 | 802 => WeatherCondition::PartlyCloudy,
+        803..=899 => WeatherCondition::Cloudy,
+        _ => WeatherCondition::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather::mock;
+
+    const RESPONSE: &str = r#"{
+        "weather": [{"id": 501, "main": "Rain", "description": "moderate rain", "icon": "10d"}],
+        "main": {"temp": 12.4, "feels_like": 11.1, "temp_min": 10.0, "temp_max": 14.0,
+                 "pressure": 1012, "humidity": 81},
+        "wind": {"speed": 4.6, "deg": 230},
+        "name": "Berlin"
+    }"#;
+
+    fn berlin() -> GeoData {
+        GeoData {
+            latitude: 52.52,
+            longitude: 13.41,
+            city: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_current_weather() {
+        let (base_url, request) = mock::serve_once(200, RESPONSE).await;
+        let provider = OpenWeatherMap::with_base_url(&base_url, "secret");
+
+        let weather = provider.current(&Client::new(), &berlin()).await.unwrap();
+
+        let request = request.await.unwrap();
+        assert!(request.starts_with("GET /data/2.5/weather?"), "{request}");
+        assert!(request.contains("lat=52.52&lon=13.41&appid=secret&units=metric"));
+        assert_eq!(weather.name, "Berlin");
+        assert_eq!(weather.temp_c, 12.4);
+        assert_eq!(weather.feels_like_c, 11.1);
+        assert_eq!(weather.humidity, 81.0);
+        assert_eq!(weather.pressure, 1012.0);
+        assert_eq!(weather.wind_speed, 4.6);
+        assert_eq!(weather.wind_direction, 230.0);
+        assert_eq!(weather.description, "moderate rain");
+        assert_eq!(weather.condition, WeatherCondition::Rain);
+    }
+
+    #[tokio::test]
+    async fn test_forecast_summarizes_days() {
+        // 2026-10-18 09:00, 12:00, 21:00 and 2026-10-19 00:00 in UTC+2.
+        let body = r#"{
+            "list": [
+                {"dt": 1792306800, "main": {"temp": 7.0}, "weather": [{"id": 800, "description": "clear sky"}], "pop": 0},
+                {"dt": 1792317600, "main": {"temp": 13.5}, "weather": [{"id": 500, "description": "light rain"}], "pop": 0.6},
+                {"dt": 1792350000, "main": {"temp": 9.0}, "weather": [{"id": 804, "description": "overcast clouds"}], "pop": 0.2},
+                {"dt": 1792360800, "main": {"temp": 6.5}, "weather": [{"id": 600, "description": "light snow"}]}
+            ],
+            "city": {"name": "Berlin", "timezone": 7200}
+        }"#;
+        let (base_url, request) = mock::serve_once(200, body).await;
+        let provider = OpenWeatherMap::with_base_url(&base_url, "secret");
+
+        let forecast = provider.forecast(&Client::new(), &berlin()).await.unwrap();
+
+        assert!(request
+            .await
+            .unwrap()
+            .starts_with("GET /data/2.5/forecast?"));
+        assert_eq!(forecast.hourly.len(), 4);
+        assert_eq!(
+            forecast.hourly[1].time.to_rfc3339(),
+            "2026-10-18T12:00:00+02:00"
+        );
+        assert_eq!(forecast.hourly[1].precipitation_chance, Some(60.0));
+
+        assert_eq!(forecast.daily.len(), 2);
+        let today = &forecast.daily[0];
+        assert_eq!(today.date.to_string(), "2026-10-18");
+        assert_eq!((today.min_c, today.max_c), (7.0, 13.5));
+        assert_eq!(today.condition, WeatherCondition::Rain);
+        assert_eq!(today.precipitation_chance, Some(60.0));
+        assert_eq!(forecast.daily[1].condition, WeatherCondition::Snow);
+        assert_eq!(forecast.daily[1].precipitation_chance, None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_key() {
+        let (base_url, _) = mock::serve_once(401, r#"{"cod": 401}"#).await;
+        let provider = OpenWeatherMap::with_base_url(&base_url, "wrong");
+
+        let error = provider
+            .current(&Client::new(), &berlin())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, WeatherError::Status(401)), "{error}");
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Exponential backoff for failed fetches. Each delay is drawn from the upper
+/// half of the current step ("equal jitter"), so clients that failed together
+/// do not retry in lockstep.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    failures: u32,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max: max.max(base),
+            failures: 0,
+        }
+    }
+
+    /// Delay before the next attempt after another failure.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.delay(self.failures, random_fraction());
+        self.failures = self.failures.saturating_add(1);
+        delay
+    }
+
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+
+    /// `jitter` in `0.0..=1.0` picks a point between half and all of the step.
+    fn delay(&self, failures: u32, jitter: f64) -> Duration {
+        let step = self
+            .base
+            .saturating_mul(2u32.saturating_pow(failures.min(16)))
+            .min(self.max);
+        step.mul_f64(0.5 + jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+/// Uniform-ish value in `0.0..1.0` from the randomly keyed std hasher, which
+/// is plenty for spreading out retries.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles_up_to_max() {
+        let backoff = Backoff::new(Duration::from_secs(5), Duration::from_secs(60));
+        assert_eq!(backoff.delay(0, 1.0), Duration::from_secs(5));
+        assert_eq!(backoff.delay(1, 1.0), Duration::from_secs(10));
+        assert_eq!(backoff.delay(3, 1.0), Duration::from_secs(40));
+        assert_eq!(backoff.delay(4, 1.0), Duration::from_secs(60));
+        assert_eq!(backoff.delay(u32::MAX, 1.0), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_jitter_stays_in_upper_half() {
+        let backoff = Backoff::new(Duration::from_secs(8), Duration::from_secs(60));
+        assert_eq!(backoff.delay(0, 0.0), Duration::from_secs(4));
+        assert_eq!(backoff.delay(0, 0.5), Duration::from_secs(6));
+
+        let mut backoff = backoff;
+        for failures in 0..6 {
+            let step = Duration::from_secs(8 * 2u64.pow(failures)).min(Duration::from_secs(60));
+            let delay = backoff.next_delay();
+            assert!(
+                delay >= step / 2 && delay <= step,
+                "{delay:?} for step {step:?}"
+            );
+        }
+        backoff.reset();
+        assert!(backoff.next_delay() <= Duration::from_secs(8));
+    }
+}
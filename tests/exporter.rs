@@ -0,0 +1,73 @@
+use tokio::{net::TcpListener, sync::watch};
+use tuitask::{
+    exporter::{self, ExporterSnapshot},
+    types::{Status, SystemData},
+};
+
+async fn start(snapshot: ExporterSnapshot) -> (String, watch::Sender<ExporterSnapshot>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = watch::channel(snapshot);
+    tokio::spawn(exporter::serve(listener, rx));
+    (format!("http://{addr}"), tx)
+}
+
+fn snapshot() -> ExporterSnapshot {
+    let mut system = SystemData {
+        total_memory: 16.0,
+        used_memory: 4.0,
+        ..SystemData::default()
+    };
+    system.cpu.usage = 42.0;
+    system.host.load_one = 1.5;
+    ExporterSnapshot {
+        system,
+        todo_counts: vec![(Status::Todo, 2), (Status::Done, 5)],
+    }
+}
+
+#[tokio::test]
+async fn test_metrics_endpoint_serves_exposition_format() {
+    let (base, _tx) = start(snapshot()).await;
+
+    let response = reqwest::get(format!("{base}/metrics")).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let content_type = response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .to_owned();
+    assert!(content_type.starts_with("text/plain; version=0.0.4"));
+
+    let body = response.text().await.unwrap();
+    assert!(body.contains("tuitask_cpu_usage_percent 42\n"));
+    assert!(body.contains("tuitask_memory_used_bytes 4294967296\n"));
+    assert!(body.contains("tuitask_load_average{period=\"1m\"} 1.5\n"));
+    assert!(body.contains("tuitask_todos{status=\"Todo\"} 2\n"));
+    assert!(body.contains("tuitask_todos{status=\"Done\"} 5\n"));
+}
+
+#[tokio::test]
+async fn test_metrics_follow_published_snapshots() {
+    let (base, tx) = start(snapshot()).await;
+
+    let mut updated = snapshot();
+    updated.todo_counts = vec![(Status::Active, 7)];
+    tx.send_replace(updated);
+
+    let body = reqwest::get(format!("{base}/metrics"))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("tuitask_todos{status=\"Active\"} 7\n"));
+    assert!(!body.contains("status=\"Todo\""));
+}
+
+#[tokio::test]
+async fn test_unknown_path_is_not_found() {
+    let (base, _tx) = start(snapshot()).await;
+
+    let response = reqwest::get(format!("{base}/nope")).await.unwrap();
+    assert_eq!(response.status(), 404);
+}